@@ -0,0 +1,187 @@
+//! The known answers to the puzzle inputs, checked by the runner and the tests.
+//!
+//! Everyone gets different puzzle inputs, so the answers are recorded per input, identified by its
+//! [`input_hash`](crate::report::input_hash).
+//! An answer of `null` marks a part whose answer is not known yet,
+//! so the input is accounted for without claiming that the current output is right.
+
+use crate::prelude::*;
+use crate::solution::Part;
+#[cfg(test)]
+use crate::{report::input_hash, solution::Entry};
+use std::io::ErrorKind;
+
+/// Where the runner and the tests look for the answers by default
+pub const ANSWERS_PATH: &str = "input/2022/answers.json";
+
+/// The known answer of one part for one input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answer {
+    pub day: u32,
+    pub part: u32,
+    /// [`input_hash`] of the input
+    pub input: String,
+    /// `None` if the answer is not known yet
+    pub answer: Option<String>,
+}
+
+/// How an answer compares to the recorded one.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Check {
+    Correct,
+    Wrong,
+    /// The input is recorded, but its answer is not known yet
+    Unknown,
+    /// Nothing is recorded for the input
+    Missing,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    /// Read the answers from a JSON file. A missing file is the same as an empty one.
+    pub fn load(path: &str) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json).map_err(|err| anyhow!("Invalid {path}: {err:#}")),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(anyhow!("Failed to read {path}: {err}")),
+        }
+    }
+
+    /// Parse a JSON list of [`Answer`]s, each day, part and input may only appear once.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let answers: Vec<Answer> = serde_json::from_str(json)?;
+        if let Some(duplicate) = answers
+            .iter()
+            .duplicates_by(|a| (a.day, a.part, &a.input))
+            .next()
+        {
+            return Err(anyhow!(
+                "Day {} part {} has more than one answer for input {}",
+                duplicate.day,
+                duplicate.part,
+                duplicate.input
+            ));
+        }
+        Ok(Self { answers })
+    }
+
+    /// The recorded answer of a part for the input with the given hash.
+    pub fn get(&self, day: u32, part: Part, input: &str) -> Option<&Answer> {
+        self.answers
+            .iter()
+            .find(|a| a.day == day && a.part == part.number() && a.input == input)
+    }
+
+    pub fn check(&self, day: u32, part: Part, input: &str, answer: &str) -> Check {
+        match self.get(day, part, input).map(|a| a.answer.as_deref()) {
+            None => Check::Missing,
+            Some(None) => Check::Unknown,
+            Some(Some(expected)) if expected == answer => Check::Correct,
+            Some(Some(_)) => Check::Wrong,
+        }
+    }
+}
+
+#[cfg(test)]
+/// Run every solver of `entry` on `input` and describe each error, wrong or unrecorded answer.
+fn problems(entry: &Entry, input: &str, answers: &Answers) -> Vec<String> {
+    let hash = input_hash(input);
+    let mut problems = Vec::new();
+    for solver in entry.solvers() {
+        let name = format!(
+            "Day {} part {}{}",
+            entry.day(),
+            solver.part,
+            solver
+                .variant
+                .map(|v| format!(" ({v})"))
+                .unwrap_or_default()
+        );
+        let answer = match entry.run(input, solver) {
+            Ok(answer) => answer,
+            Err(err) => {
+                problems.push(format!("{name}: error: {err:#}"));
+                continue;
+            }
+        };
+        match answers
+            .get(entry.day(), solver.part, &hash)
+            .map(|a| a.answer.as_deref())
+        {
+            None => problems.push(format!(
+                "{name}: no answer recorded for input {hash}, the answer was {answer:?}"
+            )),
+            Some(Some(expected)) if expected != answer => problems.push(format!(
+                "{name}: wrong answer {answer:?}, expected {expected:?}"
+            )),
+            Some(_) => {}
+        }
+    }
+    problems
+}
+
+#[test]
+fn test_answers() -> Result<()> {
+    let answers = Answers::load(ANSWERS_PATH)?;
+    let mut all = Vec::new();
+    for entry in crate::solution::SOLUTIONS {
+        // Only some of the inputs are checked in
+        let Ok(input) = std::fs::read_to_string(format!("input/2022/day{}.txt", entry.day()))
+        else {
+            continue;
+        };
+        all.extend(problems(entry, &input, &answers));
+    }
+    assert!(
+        all.is_empty(),
+        "Checking {ANSWERS_PATH}:\n{}",
+        all.join("\n")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_check() -> Result<()> {
+    let answers = Answers::from_json(
+        r#"[
+            {"day": 1, "part": 1, "input": "abc", "answer": "24000"},
+            {"day": 1, "part": 2, "input": "abc", "answer": null}
+        ]"#,
+    )?;
+    assert_eq!(Check::Correct, answers.check(1, Part::One, "abc", "24000"));
+    assert_eq!(Check::Wrong, answers.check(1, Part::One, "abc", "45000"));
+    assert_eq!(Check::Unknown, answers.check(1, Part::Two, "abc", "45000"));
+    assert_eq!(Check::Missing, answers.check(1, Part::One, "def", "24000"));
+    assert_eq!(Check::Missing, answers.check(2, Part::One, "abc", "24000"));
+
+    let duplicate = r#"[
+        {"day": 1, "part": 1, "input": "abc", "answer": "1"},
+        {"day": 1, "part": 1, "input": "abc", "answer": "2"}
+    ]"#;
+    assert!(Answers::from_json(duplicate).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_problems() -> Result<()> {
+    let entry = crate::solution::find(1).unwrap();
+    let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+    let hash = input_hash(input);
+    let answers = Answers::from_json(&format!(
+        r#"[
+            {{"day": 1, "part": 1, "input": "{hash}", "answer": "24000"}},
+            {{"day": 1, "part": 2, "input": "{hash}", "answer": "1"}}
+        ]"#
+    ))?;
+    let problems = problems(entry, input, &answers);
+    assert_eq!(
+        vec![r#"Day 1 part 2: wrong answer "45000", expected "1""#.to_string()],
+        problems
+    );
+    Ok(())
+}
@@ -69,72 +69,67 @@
 //! [magical energy]: https://adventofcode.com/2018/day/25
 
 use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
 
-#[aoc_runner_derive::aoc_generator(day1)]
-fn input_generator(input: &str) -> Vec<Vec<u32>> {
+fn input_generator(input: &str) -> Result<Vec<Vec<u32>>> {
     input
         .split("\n\n")
         .map(|segment| {
             segment
                 .split('\n')
-                .map(|line| line.parse().unwrap())
+                .map(|line| {
+                    line.parse()
+                        .map_err(|err| anyhow!("Invalid calories {line:?}: {err}"))
+                })
                 .collect()
         })
         .collect()
 }
 
-#[aoc_runner_derive::aoc(day1, part1)]
-fn part1(input: &[Vec<u32>]) -> u32 {
-    input.iter().map(|v| v.iter().sum()).max().unwrap()
+fn part1(input: &[Vec<u32>]) -> Result<u32> {
+    input
+        .iter()
+        .map(|v| v.iter().sum())
+        .max()
+        .ok_or_else(|| anyhow!("No elves"))
 }
 
-#[aoc_runner_derive::aoc(day1, part2)]
-fn part2(input: &[Vec<u32>]) -> u32 {
+fn part2(input: &[Vec<u32>]) -> Result<u32> {
+    if input.len() < 3 {
+        return Err(anyhow!("Only {} elves, expected at least 3", input.len()));
+    }
     let mut sums = input.iter().map(|v| v.iter().sum()).collect_vec();
     sums.sort_unstable();
-    sums[sums.len() - 3..].iter().sum()
+    Ok(sums[sums.len() - 3..].iter().sum())
 }
 
-#[cfg(test)]
-static TEST_INPUT_1: &str = r"1000
-2000
-3000
-
-4000
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day01>();
 
-5000
-6000
+struct Day01;
 
-7000
-8000
-9000
+impl Solution for Day01 {
+    type Input = Vec<Vec<u32>>;
 
-10000";
+    const DAY: u32 = 1;
 
-#[test]
-fn test_part1() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1);
-    assert_eq!(24000, part1(&values));
-    Ok(())
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
 
-#[test]
-fn test_part1_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2022/day1.txt").trim());
-    assert_eq!(74711, part1(&values));
-    Ok(())
-}
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
 
-#[test]
-fn test_part2() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1);
-    assert_eq!(45000, part2(&values));
-    Ok(())
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
 }
 
 #[test]
-fn test_part2_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2022/day1.txt").trim());
-    assert_eq!(209481, part2(&values));
+fn test_invalid_input() -> Result<()> {
+    assert!(input_generator("1000\n2000\n\nthree").is_err());
+    assert!(input_generator("1000\n\n\n2000").is_err());
+    assert!(part2(&input_generator("1000\n\n2000")?).is_err());
     Ok(())
 }
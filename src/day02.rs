@@ -63,6 +63,7 @@
 //! Following the Elf's instructions for the second column, _what would your total score be if everything goes exactly according to your strategy guide?_
 
 use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Part, Solution, Variant};
 
 #[derive(Inpt, Debug)]
 #[inpt(regex = r"(.) (.)")]
@@ -184,42 +185,46 @@ impl MovePart2 {
     }
 }
 
-#[aoc_runner_derive::aoc_generator(day2, part1, Complex)]
-fn input_generator_complex(input: &str) -> Vec<MovePart1> {
-    input.lines().map(|line| inpt(line).unwrap()).collect()
+fn input_generator_complex(input: &str) -> Result<Vec<MovePart1>> {
+    input
+        .lines()
+        .map(|line| inpt(line).map_err(|err| anyhow!("Invalid move {line:?}: {err}")))
+        .collect()
 }
 
-#[aoc_runner_derive::aoc_generator(day2, part2, Complex)]
-fn input_generator_part2_complex(input: &str) -> Vec<MovePart2> {
-    input.lines().map(|line| inpt(line).unwrap()).collect()
+fn input_generator_part2_complex(input: &str) -> Result<Vec<MovePart2>> {
+    input
+        .lines()
+        .map(|line| inpt(line).map_err(|err| anyhow!("Invalid move {line:?}: {err}")))
+        .collect()
 }
 
-#[aoc_runner_derive::aoc(day2, part1, Complex)]
 fn part1_complex(input: &[MovePart1]) -> i32 {
     input.iter().map(|m| m.score()).sum()
 }
 
 #[allow(clippy::identity_op)]
-#[aoc_runner_derive::aoc(day2, part1, Simple)]
-fn part1_simple(input: &str) -> i32 {
-    input.lines().map(|line| {
-        match line {
-            // First number for played symbol, second for outcome
-            "A X" => 1 + 3,
-            "A Y" => 2 + 6,
-            "A Z" => 3 + 0,
-            "B X" => 1 + 0,
-            "B Y" => 2 + 3,
-            "B Z" => 3 + 6,
-            "C X" => 1 + 6,
-            "C Y" => 2 + 0,
-            "C Z" => 3 + 3,
-            _ => panic!("Invalid input: {}", line),
-        }
-    }).sum()
+fn part1_simple(input: &str) -> Result<i32> {
+    input
+        .lines()
+        .map(|line| {
+            Ok(match line {
+                // First number for played symbol, second for outcome
+                "A X" => 1 + 3,
+                "A Y" => 2 + 6,
+                "A Z" => 3 + 0,
+                "B X" => 1 + 0,
+                "B Y" => 2 + 3,
+                "B Z" => 3 + 6,
+                "C X" => 1 + 6,
+                "C Y" => 2 + 0,
+                "C Z" => 3 + 3,
+                _ => return Err(anyhow!("Invalid move {line:?}")),
+            })
+        })
+        .sum()
 }
 
-#[aoc_runner_derive::aoc(day2, part2, Complex)]
 fn part2_complex(input: &[MovePart2]) -> i32 {
     input
         .iter()
@@ -228,82 +233,73 @@ fn part2_complex(input: &[MovePart2]) -> i32 {
 }
 
 #[allow(clippy::identity_op)]
-#[aoc_runner_derive::aoc(day2, part2, Simple)]
-fn part2_simple(input: &str) -> i32 {
-    input.lines().map(|line| {
-        match line {
-            // First number for played symbol, second for outcome
-            "A X" => 3 + 0,
-            "A Y" => 1 + 3,
-            "A Z" => 2 + 6,
-            "B X" => 1 + 0,
-            "B Y" => 2 + 3,
-            "B Z" => 3 + 6,
-            "C X" => 2 + 0,
-            "C Y" => 3 + 3,
-            "C Z" => 1 + 6,
-            _ => panic!("Invalid input: {}", line),
-        }
-    }).sum()
+fn part2_simple(input: &str) -> Result<i32> {
+    input
+        .lines()
+        .map(|line| {
+            Ok(match line {
+                // First number for played symbol, second for outcome
+                "A X" => 3 + 0,
+                "A Y" => 1 + 3,
+                "A Z" => 2 + 6,
+                "B X" => 1 + 0,
+                "B Y" => 2 + 3,
+                "B Z" => 3 + 6,
+                "C X" => 2 + 0,
+                "C Y" => 3 + 3,
+                "C Z" => 1 + 6,
+                _ => return Err(anyhow!("Invalid move {line:?}")),
+            })
+        })
+        .sum()
 }
 
-#[cfg(test)]
-static TEST_INPUT_1: &str = r"A Y
-B X
-C Z";
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day02>();
 
-#[test]
-fn test_part1_complex() -> Result<()> {
-    let values = input_generator_complex(TEST_INPUT_1);
-    assert_eq!(15, part1_complex(&values));
-    Ok(())
-}
+struct Day02;
 
-#[test]
-fn test_part1_simple() -> Result<()> {
-    let values = TEST_INPUT_1;
-    assert_eq!(15, part1_simple(values));
-    Ok(())
-}
+impl Solution for Day02 {
+    /// Kept as text, as the complex and simple solutions read it differently
+    type Input = String;
 
-#[test]
-fn test_part1_solution_complex() -> Result<()> {
-    let values = input_generator_complex(include_str!("../input/2022/day2.txt").trim());
-    assert_eq!(12679, part1_complex(&values));
-    Ok(())
-}
+    const DAY: u32 = 2;
 
-#[test]
-fn test_part1_solution_simple() -> Result<()> {
-    let values = include_str!("../input/2022/day2.txt").trim();
-    assert_eq!(12679, part1_simple(values));
-    Ok(())
-}
+    const NAME: Option<&'static str> = Some("Complex");
 
-#[test]
-fn test_part2_complex() -> Result<()> {
-    let values = input_generator_part2_complex(TEST_INPUT_1);
-    assert_eq!(12, part2_complex(&values));
-    Ok(())
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
 
-#[test]
-fn test_part2_simple() -> Result<()> {
-    let values = TEST_INPUT_1;
-    assert_eq!(12, part2_simple(values));
-    Ok(())
-}
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1_complex(&input_generator_complex(input)?).into_answer()
+    }
 
-#[test]
-fn test_part2_solution_complex() -> Result<()> {
-    let values = input_generator_part2_complex(include_str!("../input/2022/day2.txt").trim());
-    assert_eq!(14470, part2_complex(&values));
-    Ok(())
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2_complex(&input_generator_part2_complex(input)?).into_answer()
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant {
+                name: "Simple",
+                part: Part::One,
+                solve: |input| part1_simple(input).into_answer(),
+            },
+            Variant {
+                name: "Simple",
+                part: Part::Two,
+                solve: |input| part2_simple(input).into_answer(),
+            },
+        ]
+    }
 }
 
 #[test]
-fn test_part2_solution_simple() -> Result<()> {
-    let values = include_str!("../input/2022/day2.txt").trim();
-    assert_eq!(14470, part2_simple(values));
-    Ok(())
+fn test_invalid_move() {
+    let input = "A Y\nB W";
+    assert!(part1_simple(input).is_err());
+    assert!(part2_simple(input).is_err());
+    assert!(input_generator_complex(input).is_err());
+    assert!(input_generator_part2_complex(input).is_err());
 }
@@ -87,82 +87,69 @@
 //! _What is the sum of the priorities of those item types?_
 
 use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
 
-#[aoc_runner_derive::aoc(day3, part1)]
-fn part1(input: &str) -> u32 {
+/// Rucksacks with the priority of each item in them
+fn input_generator(input: &str) -> Result<Vec<Vec<u32>>> {
     input
         .lines()
-        .map(|line| {
-            let mid = line.len() / 2;
-            for c in line[..mid].chars() {
-                if line[mid..].contains(c) {
-                    return c;
-                }
-            }
-            unreachable!("no match found");
+        .map(|line| line.chars().map(priority).collect())
+        .collect()
+}
+
+fn part1(input: &[Vec<u32>]) -> Result<u32> {
+    input
+        .iter()
+        .map(|rucksack| {
+            let (first, second) = rucksack.split_at(rucksack.len() / 2);
+            first
+                .iter()
+                .find(|p| second.contains(p))
+                .ok_or_else(|| anyhow!("No item type is in both compartments"))
         })
-        .map(priority)
         .sum()
 }
 
-#[aoc_runner_derive::aoc(day3, part2)]
-fn part2(input: &str) -> u32 {
+fn part2(input: &[Vec<u32>]) -> Result<u32> {
     input
-        .lines()
+        .iter()
         .tuples()
-        .map(|(l0, l1, l2)| {
-            for c in l0.chars() {
-                if l1.contains(c) && l2.contains(c) {
-                    return c;
-                }
-            }
-            unreachable!("no match found");
+        .map(|(r0, r1, r2)| {
+            r0.iter()
+                .find(|p| r1.contains(p) && r2.contains(p))
+                .ok_or_else(|| anyhow!("No item type is carried by all three elves"))
         })
-        .map(priority)
         .sum()
 }
 
-fn priority(c: char) -> u32 {
+fn priority(c: char) -> Result<u32> {
     //convert to priority
     match c {
-        'a'..='z' => c as u32 - 'a' as u32 + 1,
-        'A'..='Z' => c as u32 - 'A' as u32 + 27,
-        _ => unreachable!("invalid char"),
+        'a'..='z' => Ok(c as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Ok(c as u32 - 'A' as u32 + 27),
+        _ => Err(anyhow!("Invalid item type: {c:?}")),
     }
 }
 
-#[cfg(test)]
-static TEST_INPUT_1: &str = r"vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw";
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day03>();
 
-#[test]
-fn test_part1() -> Result<()> {
-    let values = TEST_INPUT_1;
-    assert_eq!(157, part1(values));
-    Ok(())
-}
+struct Day03;
 
-#[test]
-fn test_part1_solution() -> Result<()> {
-    let values = include_str!("../input/2022/day3.txt").trim();
-    assert_eq!(8039, part1(&values));
-    Ok(())
-}
+impl Solution for Day03 {
+    type Input = Vec<Vec<u32>>;
 
-#[test]
-fn test_part2() -> Result<()> {
-    let values = TEST_INPUT_1;
-    assert_eq!(45000, part2(&values));
-    Ok(())
-}
+    const DAY: u32 = 3;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
 
-#[test]
-fn test_part2_solution() -> Result<()> {
-    let values = include_str!("../input/2022/day3.txt").trim();
-    assert_eq!(2510, part2(&values));
-    Ok(())
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
 }
@@ -72,6 +72,7 @@
 //! _In how many assignment pairs do the ranges overlap?_
 
 use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
 
 #[derive(Inpt, Debug)]
 #[inpt(regex = r"(\d+)-(\d+),(\d+)-(\d+)")]
@@ -82,24 +83,16 @@ struct AssignmentPair {
     second_to: usize,
 }
 
-// #[aoc_runner_derive::aoc_generator(day4)]
-// fn input_generator(input: &str) -> Vec<Vec<u32>> {
-//     input
-//         .split("\n\n")
-//         .map(|segment| {
-//             segment
-//                 .split('\n')
-//                 .map(|line| line.parse().unwrap())
-//                 .collect()
-//         })
-//         .collect()
-// }
-
-#[aoc_runner_derive::aoc(day4, part1)]
-fn part1(input: &str) -> u32 {
+fn input_generator(input: &str) -> Result<Vec<AssignmentPair>> {
     input
         .lines()
-        .map(|line| inpt::<AssignmentPair>(line).unwrap())
+        .map(|line| inpt(line).map_err(|err| anyhow!("Invalid assignment pair {line:?}: {err}")))
+        .collect()
+}
+
+fn part1(input: &[AssignmentPair]) -> u32 {
+    input
+        .iter()
         .filter(|ap| {
             let r0 = ap.first_from..=ap.first_to;
             let r1 = ap.second_from..=ap.second_to;
@@ -110,11 +103,9 @@ fn part1(input: &str) -> u32 {
         .count() as u32
 }
 
-#[aoc_runner_derive::aoc(day4, part2)]
-fn part2(input: &str) -> u32 {
+fn part2(input: &[AssignmentPair]) -> u32 {
     input
-        .lines()
-        .map(|line| inpt::<AssignmentPair>(line).unwrap())
+        .iter()
         .filter(|ap| {
             let r0 = ap.first_from..=ap.first_to;
             let r1 = ap.second_from..=ap.second_to;
@@ -127,38 +118,25 @@ fn part2(input: &str) -> u32 {
         .count() as u32
 }
 
-#[cfg(test)]
-static TEST_INPUT_1: &str = r"2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8";
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day04>();
 
-#[test]
-fn test_part1() -> Result<()> {
-    let values = TEST_INPUT_1;
-    assert_eq!(2, part1(values));
-    Ok(())
-}
+struct Day04;
 
-#[test]
-fn test_part1_solution() -> Result<()> {
-    let values = include_str!("../input/2022/day4.txt").trim();
-    assert_eq!(471, part1(values));
-    Ok(())
-}
+impl Solution for Day04 {
+    type Input = Vec<AssignmentPair>;
 
-#[test]
-fn test_part2() -> Result<()> {
-    let values = TEST_INPUT_1;
-    assert_eq!(4, part2(values));
-    Ok(())
-}
+    const DAY: u32 = 4;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
 
-#[test]
-fn test_part2_solution() -> Result<()> {
-    let values = include_str!("../input/2022/day4.txt").trim();
-    assert_eq!(888, part2(values));
-    Ok(())
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
 }
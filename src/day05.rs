@@ -141,6 +141,7 @@
 //! _After the rearrangement procedure completes, what crate ends up on top of each stack?_
 
 use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
 
 #[derive(Clone, Debug)]
 struct Stacks {
@@ -171,19 +172,20 @@ struct Move {
     to: usize,
 }
 
-#[aoc_runner_derive::aoc_generator(day5)]
-fn input_generator(input: &str) -> (Stacks, Vec<Move>) {
-    let (stack_img, moves) = input.split_once("\n\n").unwrap();
+fn input_generator(input: &str) -> Result<(Stacks, Vec<Move>)> {
+    let (stack_img, moves) = input
+        .split_once("\n\n")
+        .ok_or_else(|| anyhow!("Missing empty line between the stacks and the moves"))?;
 
     let mut stacks = Vec::new();
-    stack_img.lines().for_each(|line| {
-        dbg!(&line);
+    for line in stack_img.lines() {
         let mut chars = line.chars().fuse();
         for idx in 0.. {
             let first_char = chars.next();
             if first_char == Some('[') {
-                let b = chars.next().unwrap();
-                assert_eq!(Some(']'), chars.next());
+                let (Some(b), Some(']')) = (chars.next(), chars.next()) else {
+                    return Err(anyhow!("Invalid crate in {line:?}"));
+                };
                 // Optional whitespace
                 let _ = chars.next();
 
@@ -199,85 +201,103 @@ fn input_generator(input: &str) -> (Stacks, Vec<Move>) {
                 break;
             }
         }
-    });
+    }
     // We parsed the image top down, but we need to invert the stacks to ensure the first parsed entry is on the top.
     stacks.iter_mut().for_each(|s| s.reverse());
 
     let moves = moves
         .lines()
-        .map(|line| inpt::<Move>(line).unwrap())
-        .collect();
-    (Stacks { stacks }, moves)
+        .map(|line| {
+            let m = inpt::<Move>(line).map_err(|err| anyhow!("Invalid move {line:?}: {err}"))?;
+            if !(1..=stacks.len()).contains(&m.from) || !(1..=stacks.len()).contains(&m.to) {
+                return Err(anyhow!("Move {line:?} uses a stack that does not exist"));
+            }
+            Ok(m)
+        })
+        .collect::<Result<_>>()?;
+    Ok((Stacks { stacks }, moves))
 }
 
-#[aoc_runner_derive::aoc(day5, part1)]
-fn part1((stacks, moves): &(Stacks, Vec<Move>)) -> String {
+/// The crates on top of each stack.
+fn top_crates(stacks: &Stacks) -> Result<String> {
+    stacks
+        .stacks
+        .iter()
+        .enumerate()
+        .map(|(idx, s)| {
+            s.last()
+                .ok_or_else(|| anyhow!("Stack {} is empty", idx + 1))
+        })
+        .collect()
+}
+
+fn part1((stacks, moves): &(Stacks, Vec<Move>)) -> Result<String> {
     let mut stacks = stacks.clone();
 
     for &Move { amount, from, to } in moves {
         // println!("{}", stacks.display());
         // println!("Move {amount} from {from} to {to}");
         for _ in 0..amount {
-            let b = stacks.stacks[from - 1].pop().unwrap();
+            let b = stacks.stacks[from - 1]
+                .pop()
+                .ok_or_else(|| anyhow!("Stack {from} is empty"))?;
             stacks.stacks[to - 1].push(b);
         }
     }
 
-    stacks.stacks.iter_mut().map(|s| s.pop().unwrap()).collect()
+    top_crates(&stacks)
 }
 
-#[aoc_runner_derive::aoc(day5, part2)]
-fn part2((stacks, moves): &(Stacks, Vec<Move>)) -> String {
+fn part2((stacks, moves): &(Stacks, Vec<Move>)) -> Result<String> {
     let mut stacks = stacks.clone();
 
     for &Move { amount, from, to } in moves {
         // println!("{}", stacks.display());
         // println!("Move {amount} from {from} to {to}");
         let from_stack_len = stacks.stacks[from - 1].len();
+        if amount > from_stack_len {
+            return Err(anyhow!("Stack {from} has fewer than {amount} crates"));
+        }
         let buffer = stacks.stacks[from - 1]
             .drain(from_stack_len - amount..)
             .collect::<Vec<_>>();
         stacks.stacks[to - 1].extend(buffer);
     }
 
-    stacks.stacks.iter_mut().map(|s| s.pop().unwrap()).collect()
+    top_crates(&stacks)
 }
 
-#[cfg(test)]
-static TEST_INPUT_1: &str = r"    [D]
-[N] [C]
-[Z] [M] [P]
- 1   2   3
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day05>();
 
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2";
+struct Day05;
 
-#[test]
-fn test_part1() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1);
-    assert_eq!("CMZ", part1(&values));
-    Ok(())
-}
+impl Solution for Day05 {
+    type Input = (Stacks, Vec<Move>);
 
-#[test]
-fn test_part1_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2022/day5.txt"));
-    assert_eq!("ZRLJGSCTR", part1(&values));
-    Ok(())
-}
+    const DAY: u32 = 5;
 
-#[test]
-fn test_part2() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1);
-    assert_eq!("MCD", part2(&values));
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
 }
 
 #[test]
-fn test_part2_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2022/day5.txt"));
-    assert_eq!("", part2(&values));
+fn test_invalid_input() -> Result<()> {
+    assert!(input_generator("[A] [B]\n 1   2 \nmove 1 from 1 to 2").is_err());
+    assert!(input_generator("[A] [B\n 1   2 \n\nmove 1 from 1 to 2").is_err());
+    assert!(input_generator("[A] [B]\n 1   2 \n\nmove 1 from 1 to 3").is_err());
+    // Moving more crates than there are
+    let input = input_generator("[A] [B]\n 1   2 \n\nmove 2 from 1 to 2")?;
+    assert!(part1(&input).is_err());
+    assert!(part2(&input).is_err());
     Ok(())
 }
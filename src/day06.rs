@@ -0,0 +1,185 @@
+//! # Day 6: Tuning Trouble
+//!
+//! ## --- Part One ---
+//!
+//! The preparations are finally complete; you and the Elves leave camp on foot and begin to make your way toward the _star_ fruit grove.
+//!
+//! As you move through the dense undergrowth, one of the Elves gives you a handheld _device_.
+//! He says that it has many fancy features, but the most important one to set up right now is the _communication system_.
+//!
+//! However, because he's heard you have [significant](https://adventofcode.com/2016/day/6) [experience](https://adventofcode.com/2016/day/25) [dealing](https://adventofcode.com/2019/day/7) [with](https://adventofcode.com/2019/day/9) [signal-based](https://adventofcode.com/2019/day/16) [systems](https://adventofcode.com/2021/day/25), he convinced the other Elves that it would be okay to give you their one malfunctioning device - surely you'll have no problem fixing it.
+//!
+//! As if inspired by comedic timing, the device emits a few colorful sparks.
+//!
+//! To be able to communicate with the Elves, the device needs to _lock on to their signal_.
+//! The signal is a series of seemingly-random characters that the device receives one at a time.
+//!
+//! To fix the communication system, you need to add a subroutine to the device that detects a _start-of-packet marker_ in the datastream.
+//! In the protocol being used by the Elves, the start of a packet is indicated by a sequence of _four characters that are all different_.
+//!
+//! The device will send your subroutine a datastream buffer (your puzzle input); your subroutine needs to identify the first position where the four most recently received characters were all different.
+//! Specifically, it needs to report the number of characters from the beginning of the buffer to the end of the first such four-character marker.
+//!
+//! For example, suppose you receive the following datastream buffer:
+//!
+//! ```text
+//! mjqjpqmgbljsphdztnvjfqwrcgsmlb
+//! ```
+//!
+//! After the first three characters (`mjq`) have been received, there haven't been enough characters received yet to find the marker.
+//! The first time a marker could occur is after the fourth character is received, making the most recent four characters `mjqj`.
+//! Because `j` is repeated, this isn't a marker.
+//!
+//! The first time a marker appears is after the _seventh_ character arrives.
+//! Once it does, the last four characters received are `jpqm`, which are all different.
+//! In this case, your subroutine should report the value _`7`_, because the first start-of-packet marker is complete after 7 characters have been processed.
+//!
+//! Here are a few more examples:
+//!
+//! * `bvwbjplbgvbhsrlpgdmjqwftvncz`: first marker after character _`5`_
+//! * `nppdvjthqldpwncqszvftbrmjlhg`: first marker after character _`6`_
+//! * `nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg`: first marker after character _`10`_
+//! * `zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw`: first marker after character _`11`_
+//!
+//! _How many characters need to be processed before the first start-of-packet marker is detected?_
+//!
+//! ## --- Part Two ---
+//!
+//! Your device's communication system is correctly detecting packets, but still isn't working.
+//! It looks like it also needs to look for _messages_.
+//!
+//! A _start-of-message marker_ is just like a start-of-packet marker, except it consists of _14 distinct characters_ rather than 4.
+//!
+//! Here are the first positions of start-of-message markers for all of the above examples:
+//!
+//! * `mjqjpqmgbljsphdztnvjfqwrcgsmlb`: first marker after character _`19`_
+//! * `bvwbjplbgvbhsrlpgdmjqwftvncz`: first marker after character _`23`_
+//! * `nppdvjthqldpwncqszvftbrmjlhg`: first marker after character _`23`_
+//! * `nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg`: first marker after character _`29`_
+//! * `zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw`: first marker after character _`26`_
+//!
+//! _How many characters need to be processed before the first start-of-message marker is detected?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Part, Solution, Variant};
+
+/// Number of distinct characters forming a start-of-packet marker
+const PACKET_MARKER_LEN: usize = 4;
+/// Number of distinct characters forming a start-of-message marker
+const MESSAGE_MARKER_LEN: usize = 14;
+
+/// Check every window on its own whether all characters are distinct.
+fn find_marker_naive(input: &str, marker_len: usize) -> Result<usize> {
+    input
+        .as_bytes()
+        .windows(marker_len)
+        .position(|window| window.iter().all_unique())
+        .map(|pos| pos + marker_len)
+        .ok_or_else(|| anyhow!("No marker of length {marker_len} found"))
+}
+
+/// Slide a window over the input and keep count of how often each character occurs in it.
+///
+/// Each character is only touched twice, once when entering and once when leaving the window.
+fn find_marker_window(input: &str, marker_len: usize) -> Result<usize> {
+    let input = input.as_bytes();
+    let mut counts = [0u8; 256];
+    // Number of characters occurring more than once in the window
+    let mut duplicates = 0;
+
+    for (idx, &c) in input.iter().enumerate() {
+        counts[c as usize] += 1;
+        if counts[c as usize] == 2 {
+            duplicates += 1;
+        }
+
+        if idx >= marker_len {
+            let old = input[idx - marker_len] as usize;
+            counts[old] -= 1;
+            if counts[old] == 1 {
+                duplicates -= 1;
+            }
+        }
+
+        if idx + 1 >= marker_len && duplicates == 0 {
+            return Ok(idx + 1);
+        }
+    }
+    Err(anyhow!("No marker of length {marker_len} found"))
+}
+
+fn part1_naive(input: &str) -> Result<usize> {
+    find_marker_naive(input, PACKET_MARKER_LEN)
+}
+
+fn part1_window(input: &str) -> Result<usize> {
+    find_marker_window(input, PACKET_MARKER_LEN)
+}
+
+fn part2_naive(input: &str) -> Result<usize> {
+    find_marker_naive(input, MESSAGE_MARKER_LEN)
+}
+
+fn part2_window(input: &str) -> Result<usize> {
+    find_marker_window(input, MESSAGE_MARKER_LEN)
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day06>();
+
+struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+
+    const DAY: u32 = 6;
+
+    const NAME: Option<&'static str> = Some("Window");
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1_window(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2_window(input).into_answer()
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant {
+                name: "Naive",
+                part: Part::One,
+                solve: |input| part1_naive(input).into_answer(),
+            },
+            Variant {
+                name: "Naive",
+                part: Part::Two,
+                solve: |input| part2_naive(input).into_answer(),
+            },
+        ]
+    }
+}
+
+#[test]
+fn test_find_marker() -> Result<()> {
+    for find_marker in [find_marker_naive, find_marker_window] {
+        assert_eq!(
+            7,
+            find_marker("mjqjpqmgbljsphdztnvjfqwrcgsmlb", PACKET_MARKER_LEN)?
+        );
+        assert_eq!(
+            26,
+            find_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", MESSAGE_MARKER_LEN)?
+        );
+        // The marker can end on the very last character
+        assert_eq!(4, find_marker("abcd", PACKET_MARKER_LEN)?);
+        assert!(find_marker("abc", PACKET_MARKER_LEN).is_err());
+        assert!(find_marker("abcabcabcabc", PACKET_MARKER_LEN).is_err());
+        assert!(find_marker("", PACKET_MARKER_LEN).is_err());
+    }
+    Ok(())
+}
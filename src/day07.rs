@@ -0,0 +1,393 @@
+//! # Day 7: No Space Left On Device
+//!
+//! ## --- Part One ---
+//!
+//! You can hear birds chirping and raindrops hitting leaves as the expedition proceeds.
+//! Occasionally, you can even hear much louder sounds in the distance; how big do the animals get out here, anyway?
+//!
+//! The device the Elves gave you has problems with more than just its communication system.
+//! You try to run a system update:
+//!
+//! ```text
+//! $ system-update --please --pretty-please-with-sugar-on-top
+//! Error: No space left on device
+//! ```
+//!
+//! Perhaps you can delete some files to make space for the update?
+//!
+//! You browse around the filesystem to assess the situation and save the resulting terminal output (your puzzle input).
+//! For example:
+//!
+//! ```text
+//! $ cd /
+//! $ ls
+//! dir a
+//! 14848514 b.txt
+//! 8504156 c.dat
+//! dir d
+//! $ cd a
+//! $ ls
+//! dir e
+//! 29116 f
+//! 2557 g
+//! 62596 h.lst
+//! $ cd e
+//! $ ls
+//! 584 i
+//! $ cd ..
+//! $ cd ..
+//! $ cd d
+//! $ ls
+//! 4060174 j
+//! 8033020 d.log
+//! 5626152 d.ext
+//! 7214296 k
+//! ```
+//!
+//! The filesystem consists of a tree of files (plain data) and directories (which can contain other directories or files).
+//! The outermost directory is called `/`.
+//! You can navigate around the filesystem, moving into or out of directories and listing the contents of the directory you're currently in.
+//!
+//! Within the terminal output, lines that begin with `$` are _commands you executed_, very much like some modern computers:
+//!
+//! * `cd` means _change directory_.
+//!     This changes which directory is the current directory, but the specific result depends on the argument:
+//!     * `cd x` moves _in_ one level: it looks in the current directory for the directory named `x` and makes it the current directory.
+//!     * `cd ..` moves _out_ one level: it finds the directory that contains the current directory, then makes that directory the current directory.
+//!     * `cd /` switches the current directory to the outermost directory, `/`.
+//! * `ls` means _list_.
+//!     It prints out all of the files and directories immediately contained by the current directory:
+//!     * `123 abc` means that the current directory contains a file named `abc` with size `123`.
+//!     * `dir xyz` means that the current directory contains a directory named `xyz`.
+//!
+//! Given the commands and output in the example above, you can determine that the filesystem looks visually like this:
+//!
+//! ```text
+//! - / (dir)
+//!   - a (dir)
+//!     - e (dir)
+//!       - i (file, size=584)
+//!     - f (file, size=29116)
+//!     - g (file, size=2557)
+//!     - h.lst (file, size=62596)
+//!   - b.txt (file, size=14848514)
+//!   - c.dat (file, size=8504156)
+//!   - d (dir)
+//!     - j (file, size=4060174)
+//!     - d.log (file, size=8033020)
+//!     - d.ext (file, size=5626152)
+//!     - k (file, size=7214296)
+//! ```
+//!
+//! Here, there are four directories: `/` (the outermost directory), `a` and `d` (which are in `/`), and `e` (which is in `a`).
+//! These directories also contain files of various sizes.
+//!
+//! Since the disk is full, your first step should probably be to find directories that are good candidates for deletion.
+//! To do this, you need to determine the _total size_ of each directory.
+//! The total size of a directory is the sum of the sizes of the files it contains, directly or indirectly.
+//! (Directories themselves do not count as having any intrinsic size.)
+//!
+//! The total sizes of the directories above can be found as follows:
+//!
+//! * The total size of directory `e` is _584_ because it contains a single file `i` of size 584 and no other directories.
+//! * The directory `a` has total size _94853_ because it contains files `f` (size 29116), `g` (size 2557), and `h.lst` (size 62596), plus file `i` indirectly (`a` contains `e` which contains `i`).
+//! * Directory `d` has total size _24933642_.
+//! * As the outermost directory, `/` contains every file.
+//!     Its total size is _48381165_, the sum of the size of every file.
+//!
+//! To begin, find all of the directories with a total size of _at most 100000_, then calculate the sum of their total sizes.
+//! In the example above, these directories are `a` and `e`; the sum of their total sizes is _`95437`_ (94853 + 584).
+//! (As in this example, this process can count files more than once!)
+//!
+//! Find all of the directories with a total size of at most 100000.
+//! _What is the sum of the total sizes of those directories?_
+//!
+//! ## --- Part Two ---
+//!
+//! Now, you're ready to choose a directory to delete.
+//!
+//! The total disk space available to the filesystem is _`70000000`_.
+//! To run the update, you need unused space of at least _`30000000`_.
+//! You need to find a directory you can delete that will _free up enough space_ to run the update.
+//!
+//! In the example above, the total size of the outermost directory (and thus the total amount of used space) is `48381165`; this means that the size of the _unused_ space must currently be `21618835`, which isn't quite the `30000000` required by the update.
+//! Therefore, the update still requires a directory with total size of at least `8381165` to be deleted before it can run.
+//!
+//! To achieve this, you have the following options:
+//!
+//! * Delete directory `e`, which would increase unused space by `584`.
+//! * Delete directory `a`, which would increase unused space by `94853`.
+//! * Delete directory `d`, which would increase unused space by `24933642`.
+//! * Delete directory `/`, which would increase unused space by `48381165`.
+//!
+//! Directories `e` and `a` are both too small; deleting them would not free up enough space.
+//! However, directories `d` and `/` are both big enough!
+//! Between these, choose the _smallest_: `d`, increasing unused space by _`24933642`_.
+//!
+//! Find the smallest directory that, if deleted, would free up enough space on the filesystem to run the update.
+//! _What is the total size of that directory?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+/// Directories with at most this total size count for part 1
+const SMALL_DIR_LIMIT: u64 = 100_000;
+const DISK_SIZE: u64 = 70_000_000;
+const REQUIRED_FREE_SPACE: u64 = 30_000_000;
+
+/// Index of a directory inside a [`Filesystem`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct DirId(usize);
+
+#[derive(Debug, Default, Clone)]
+struct Directory {
+    name: String,
+    /// `None` only for the root directory
+    parent: Option<DirId>,
+    subdirs: Map<String, DirId>,
+    files: Map<String, u64>,
+}
+
+/// In-memory model of the filesystem as reconstructed from a terminal transcript.
+///
+/// All directories are stored in a flat arena.
+/// A directory is always created after its parent, so every child has a larger index than its parent.
+#[derive(Debug, Clone)]
+struct Filesystem {
+    dirs: Vec<Directory>,
+}
+
+impl Filesystem {
+    const ROOT: DirId = DirId(0);
+
+    fn new() -> Self {
+        Self {
+            dirs: vec![Directory {
+                name: "/".to_string(),
+                ..Default::default()
+            }],
+        }
+    }
+
+    fn dir(&self, id: DirId) -> &Directory {
+        &self.dirs[id.0]
+    }
+
+    /// Return the subdirectory `name` of `parent`, creating it if it does not exist yet.
+    fn subdir(&mut self, parent: DirId, name: &str) -> Result<DirId> {
+        let dir = &self.dirs[parent.0];
+        if dir.files.contains_key(name) {
+            return Err(anyhow!(
+                "{name} is listed as a file and as a directory in {}",
+                self.path(parent)
+            ));
+        }
+        if let Some(&id) = dir.subdirs.get(name) {
+            return Ok(id);
+        }
+
+        let id = DirId(self.dirs.len());
+        self.dirs.push(Directory {
+            name: name.to_string(),
+            parent: Some(parent),
+            ..Default::default()
+        });
+        self.dirs[parent.0].subdirs.insert(name.to_string(), id);
+        Ok(id)
+    }
+
+    fn add_file(&mut self, parent: DirId, name: &str, size: u64) -> Result<()> {
+        if self.dirs[parent.0].subdirs.contains_key(name) {
+            return Err(anyhow!(
+                "{name} is listed as a directory and as a file in {}",
+                self.path(parent)
+            ));
+        }
+        match self.dirs[parent.0].files.insert(name.to_string(), size) {
+            Some(old_size) if old_size != size => Err(anyhow!(
+                "File {name} in {} is listed with sizes {old_size} and {size}",
+                self.path(parent)
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Absolute path of the directory, used for error messages
+    fn path(&self, id: DirId) -> String {
+        let mut components = Vec::new();
+        let mut current = Some(id);
+        while let Some(id) = current {
+            let dir = self.dir(id);
+            if dir.parent.is_some() {
+                components.push(dir.name.as_str());
+            }
+            current = dir.parent;
+        }
+        components.reverse();
+        format!("/{}", components.join("/"))
+    }
+
+    /// Total size of every directory, indexed by [`DirId`].
+    ///
+    /// Children always come after their parents, so a single reverse sweep over the arena suffices.
+    fn total_sizes(&self) -> Vec<u64> {
+        let mut sizes: Vec<u64> = self.dirs.iter().map(|d| d.files.values().sum()).collect();
+        for (idx, dir) in self.dirs.iter().enumerate().rev() {
+            if let Some(parent) = dir.parent {
+                sizes[parent.0] += sizes[idx];
+            }
+        }
+        sizes
+    }
+}
+
+impl FromStr for Filesystem {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fs = Filesystem::new();
+        let mut cwd = Filesystem::ROOT;
+        // Only accept listing output directly after an `ls`
+        let mut in_listing = false;
+
+        for (lineno, line) in s.lines().enumerate() {
+            let lineno = lineno + 1;
+            if let Some(cmd) = line.strip_prefix("$ ") {
+                in_listing = false;
+                match cmd.split_once(' ') {
+                    Some(("cd", "/")) => cwd = Filesystem::ROOT,
+                    Some(("cd", "..")) => {
+                        cwd = fs.dir(cwd).parent.ok_or_else(|| {
+                            anyhow!("Line {lineno}: Cannot change to the parent of /")
+                        })?;
+                    }
+                    Some(("cd", name)) => {
+                        cwd = fs
+                            .subdir(cwd, name)
+                            .map_err(|err| anyhow!("Line {lineno}: {err}"))?;
+                    }
+                    None if cmd == "ls" => in_listing = true,
+                    _ => return Err(anyhow!("Line {lineno}: Unknown command: {cmd}")),
+                }
+            } else {
+                if !in_listing {
+                    return Err(anyhow!("Line {lineno}: Output without an ls command: {line}"));
+                }
+                let (size, name) = line
+                    .split_once(' ')
+                    .ok_or_else(|| anyhow!("Line {lineno}: Invalid listing entry: {line}"))?;
+                let res = if size == "dir" {
+                    fs.subdir(cwd, name).map(|_| ())
+                } else {
+                    let size = size
+                        .parse()
+                        .map_err(|err| anyhow!("Line {lineno}: Invalid file size {size}: {err}"))?;
+                    fs.add_file(cwd, name, size)
+                };
+                res.map_err(|err| anyhow!("Line {lineno}: {err}"))?;
+            }
+        }
+
+        Ok(fs)
+    }
+}
+
+fn input_generator(input: &str) -> Result<Filesystem> {
+    input.parse()
+}
+
+fn part1(fs: &Filesystem) -> u64 {
+    fs.total_sizes()
+        .into_iter()
+        .filter(|&size| size <= SMALL_DIR_LIMIT)
+        .sum()
+}
+
+fn part2(fs: &Filesystem) -> Result<u64> {
+    let sizes = fs.total_sizes();
+    let used = sizes[Filesystem::ROOT.0];
+    let free = DISK_SIZE
+        .checked_sub(used)
+        .ok_or_else(|| anyhow!("The files take up {used}, more than the disk size {DISK_SIZE}"))?;
+    let missing = REQUIRED_FREE_SPACE.saturating_sub(free);
+    sizes
+        .into_iter()
+        .filter(|&size| size >= missing)
+        .min()
+        .ok_or_else(|| anyhow!("No directory frees up {missing}"))
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day07>();
+
+struct Day07;
+
+impl Solution for Day07 {
+    type Input = Filesystem;
+
+    const DAY: u32 = 7;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_directory_sizes() -> Result<()> {
+    let fs = input_generator(&example_input(7, Part::One, 1)?)?;
+    let sizes = fs.total_sizes();
+    let size_of = |path: &[&str]| -> Result<u64> {
+        let mut id = Filesystem::ROOT;
+        for name in path {
+            id = *fs
+                .dir(id)
+                .subdirs
+                .get(*name)
+                .ok_or_else(|| anyhow!("missing directory {name}"))?;
+        }
+        Ok(sizes[id.0])
+    };
+    assert_eq!(584, size_of(&["a", "e"])?);
+    assert_eq!(94853, size_of(&["a"])?);
+    assert_eq!(24933642, size_of(&["d"])?);
+    assert_eq!(48381165, size_of(&[])?);
+    Ok(())
+}
+
+#[test]
+fn test_malformed_transcripts() {
+    let err = input_generator("$ cd /\n$ cd ..").unwrap_err();
+    assert_eq!("Line 2: Cannot change to the parent of /", err.to_string());
+
+    let err = input_generator("$ cd /\n$ cd a\n$ ls\n12 f\n$ ls\n13 f").unwrap_err();
+    assert_eq!(
+        "Line 6: File f in /a is listed with sizes 12 and 13",
+        err.to_string()
+    );
+
+    // Listing the same file twice with the same size is fine
+    assert!(input_generator("$ ls\n12 f\n$ ls\n12 f").is_ok());
+
+    let err = input_generator("12 f").unwrap_err();
+    assert_eq!("Line 1: Output without an ls command: 12 f", err.to_string());
+}
+
+#[test]
+fn test_part2_disk_overfull() -> Result<()> {
+    let values = input_generator("$ cd /\n$ ls\n80000000 a")?;
+    assert_eq!(
+        "The files take up 80000000, more than the disk size 70000000",
+        part2(&values).unwrap_err().to_string()
+    );
+    Ok(())
+}
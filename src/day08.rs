@@ -0,0 +1,204 @@
+//! # Day 8: Treetop Tree House
+//!
+//! ## --- Part One ---
+//!
+//! The expedition comes across a peculiar patch of tall trees all planted carefully in a grid.
+//! The Elves explain that a previous expedition planted these trees as a reforestation effort.
+//! Now, they're curious if this would be a good location for a [tree house](https://en.wikipedia.org/wiki/Tree_house).
+//!
+//! First, determine whether there is enough tree cover here to keep a tree house _hidden_.
+//! To do this, you need to count the number of trees that are _visible from outside the grid_ when looking directly along a row or column.
+//!
+//! The Elves have already launched a [quadcopter](https://en.wikipedia.org/wiki/Quadcopter) to generate a map with the height of each tree (your puzzle input).
+//! For example:
+//!
+//! ```text
+//! 30373
+//! 25512
+//! 65332
+//! 33549
+//! 35390
+//! ```
+//!
+//! Each tree is represented as a single digit whose value is its height, where `0` is the shortest and `9` is the tallest.
+//!
+//! A tree is _visible_ if all of the other trees between it and an edge of the grid are _shorter_ than it.
+//! Only consider trees in the same row or column; that is, only look up, down, left, or right from any given tree.
+//!
+//! All of the trees around the edge of the grid are _visible_ - since they are already on the edge, there are no trees to block the view.
+//! In this example, that only leaves the _interior nine trees_ to consider:
+//!
+//! * The top-left `5` is _visible_ from the left and top.
+//!     (It isn't visible from the right or bottom since other trees of height `5` are in the way.)
+//! * The top-middle `5` is _visible_ from the top and right.
+//! * The top-right `1` is not visible from any direction; for it to be visible, there would need to only be trees of height _0_ between it and an edge.
+//! * The left-middle `5` is _visible_, but only from the right.
+//! * The center `3` is not visible from any direction; for it to be visible, there would need to be only trees of at most height `2` between it and an edge.
+//! * The right-middle `3` is _visible_ from the right.
+//! * In the bottom row, the middle `5` is _visible_, but the `3` and `4` are not.
+//!
+//! With 16 trees visible on the edge and another 5 visible in the interior, a total of _`21`_ trees are visible in this arrangement.
+//!
+//! Consider your map; _how many trees are visible from outside the grid?_
+//!
+//! ## --- Part Two ---
+//!
+//! Content with the amount of tree cover available, the Elves just need to know the best spot to build their tree house: they would like to be able to see a lot of _trees_.
+//!
+//! To measure the viewing distance from a given tree, look up, down, left, and right from that tree; stop if you reach an edge or at the first tree that is the same height or taller than the tree under consideration.
+//! (If a tree is right on the edge, at least one of its viewing distances will be zero.)
+//!
+//! The Elves don't care about distant trees taller than those found by the rules above; the proposed tree house has large [eaves](https://en.wikipedia.org/wiki/Eaves) to keep it dry, so they wouldn't be able to see higher than the tree house anyway.
+//!
+//! In the example above, consider the middle `5` in the second row:
+//!
+//! ```text
+//! 30373
+//! 25512
+//! 65332
+//! 33549
+//! 35390
+//! ```
+//!
+//! * Looking up, its view is not blocked; it can see _`1`_ tree (of height `3`).
+//! * Looking left, its view is blocked immediately; it can see only _`1`_ tree (of height `5`, right next to it).
+//! * Looking right, its view is not blocked; it can see _`2`_ trees.
+//! * Looking down, its view is blocked eventually; it can see _`2`_ trees (one of height `3`, then the tree of height `5` that blocks its view).
+//!
+//! A tree's _scenic score_ is found by _multiplying together_ its viewing distance in each of the four directions.
+//! For this tree, this is _`4`_ (found by multiplying `1 * 1 * 2 * 2`).
+//!
+//! However, you can do even better: consider the tree of height `5` in the middle of the fourth row:
+//!
+//! ```text
+//! 30373
+//! 25512
+//! 65332
+//! 33549
+//! 35390
+//! ```
+//!
+//! * Looking up, its view is blocked at _`2`_ trees (by another tree with a height of `5`).
+//! * Looking left, its view is not blocked; it can see _`2`_ trees.
+//! * Looking down, its view is also not blocked; it can see _`1`_ tree.
+//! * Looking right, its view is blocked at _`2`_ trees (by a massive tree of height `9`).
+//!
+//! This tree's scenic score is _`8`_ (`2 * 2 * 1 * 2`); this is the ideal spot for the tree house.
+//!
+//! Consider each tree on your map.
+//! _What is the highest scenic score possible for any tree?_
+
+use crate::grid::Grid;
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+fn input_generator(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(input, |c| {
+        c.to_digit(10)
+            .map(|d| d as u8)
+            .ok_or_else(|| anyhow!("Invalid tree height: {c}"))
+    })
+}
+
+/// Sweep along one line of sight, marking every tree which is taller than all trees before it.
+///
+/// A single pass per row and column from each edge keeps the whole computation linear.
+fn sweep(
+    grid: &Grid<u8>,
+    visible: &mut Grid<bool>,
+    positions: impl Iterator<Item = (usize, usize)>,
+) {
+    let mut tallest = None;
+    for pos in positions {
+        let height = grid[pos];
+        if tallest.is_none_or(|tallest| height > tallest) {
+            visible[pos] = true;
+            tallest = Some(height);
+            if height == 9 {
+                // Nothing behind can be visible anymore
+                break;
+            }
+        }
+    }
+}
+
+fn part1(grid: &Grid<u8>) -> usize {
+    let (width, height) = (grid.width(), grid.height());
+    let mut visible = Grid::new(width, height, false);
+
+    for y in 0..height {
+        sweep(grid, &mut visible, (0..width).map(|x| (x, y)));
+        sweep(grid, &mut visible, (0..width).rev().map(|x| (x, y)));
+    }
+    for x in 0..width {
+        sweep(grid, &mut visible, (0..height).map(|y| (x, y)));
+        sweep(grid, &mut visible, (0..height).rev().map(|y| (x, y)));
+    }
+
+    visible.iter().filter(|(_, &v)| v).count()
+}
+
+/// Number of trees visible from the tree at `start` when looking along `positions`.
+fn viewing_distance(
+    grid: &Grid<u8>,
+    start: (usize, usize),
+    positions: impl Iterator<Item = (usize, usize)>,
+) -> usize {
+    let height = grid[start];
+    let mut distance = 0;
+    for pos in positions {
+        distance += 1;
+        if grid[pos] >= height {
+            break;
+        }
+    }
+    distance
+}
+
+fn scenic_score(grid: &Grid<u8>, (x, y): (usize, usize)) -> usize {
+    let start = (x, y);
+    viewing_distance(grid, start, (0..y).rev().map(|y| (x, y)))
+        * viewing_distance(grid, start, (0..x).rev().map(|x| (x, y)))
+        * viewing_distance(grid, start, (x + 1..grid.width()).map(|x| (x, y)))
+        * viewing_distance(grid, start, (y + 1..grid.height()).map(|y| (x, y)))
+}
+
+fn part2(grid: &Grid<u8>) -> usize {
+    grid.positions()
+        .map(|pos| scenic_score(grid, pos))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day08>();
+
+struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<u8>;
+
+    const DAY: u32 = 8;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_scenic_score() -> Result<()> {
+    let values = input_generator(&example_input(8, Part::One, 0)?)?;
+    assert_eq!(4, scenic_score(&values, (2, 1)));
+    assert_eq!(8, scenic_score(&values, (2, 3)));
+    Ok(())
+}
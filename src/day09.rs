@@ -0,0 +1,818 @@
+//! # Day 9: Rope Bridge
+//!
+//! ## --- Part One ---
+//!
+//! This rope bridge creaks as you walk along it.
+//! You aren't sure how old it is, or whether it can even support your weight.
+//!
+//! It seems to support the Elves just fine, though.
+//! The bridge spans a gorge which was carved out by the massive river far below you.
+//!
+//! You step carefully; as you do, the ropes stretch and twist.
+//! You decide to distract yourself by modeling rope physics; maybe you can even figure out where _not_ to step.
+//!
+//! Consider a rope with a knot at each end; these knots mark the _head_ and the _tail_ of the rope.
+//! If the head moves far enough away from the tail, the tail is pulled toward the head.
+//!
+//! Due to nebulous reasoning involving [Planck lengths](https://en.wikipedia.org/wiki/Planck_units#Planck_length), you should be able to model the positions of the knots on a two-dimensional grid.
+//! Then, by following a hypothetical _series of motions_ (your puzzle input) for the head, you can determine how the tail will move.
+//!
+//! Due to the aforementioned Planck lengths, the rope must be quite short; in fact, the head (`H`) and tail (`T`) must _always be touching_ (diagonally adjacent and even overlapping both count as touching):
+//!
+//! ```text
+//! ....
+//! .TH.
+//! ....
+//!
+//! ....
+//! .H..
+//! ..T.
+//! ....
+//!
+//! ...
+//! .H. (H covers T)
+//! ...
+//! ```
+//!
+//! If the head is ever two steps directly up, down, left, or right from the tail, the tail must also move one step in that direction so it remains close enough:
+//!
+//! ```text
+//! .....    .....    .....
+//! .TH.. -> .T.H. -> ..TH.
+//! .....    .....    .....
+//!
+//! ...    ...    ...
+//! .T.    .T.    ...
+//! .H. -> ... -> .T.
+//! ...    .H.    .H.
+//! ...    ...    ...
+//! ```
+//!
+//! Otherwise, if the head and tail aren't touching and aren't in the same row or column, the tail always moves one step diagonally to keep up:
+//!
+//! ```text
+//! .....    .....    .....
+//! .....    ..H..    ..H..
+//! ..H.. -> ..... -> ..T..
+//! .T...    .T...    .....
+//! .....    .....    .....
+//!
+//! .....    .....    .....
+//! .....    .....    .....
+//! ..H.. -> ...H. -> ..TH.
+//! .T...    .T...    .....
+//! .....    .....    .....
+//! ```
+//!
+//! You just need to work out where the tail goes as the head follows a series of motions.
+//! Assume the head and the tail both start at the same position, overlapping.
+//!
+//! For example:
+//!
+//! ```text
+//! R 4
+//! U 4
+//! L 3
+//! D 1
+//! R 4
+//! D 1
+//! L 5
+//! R 2
+//! ```
+//!
+//! This series of motions moves the head _right_ four steps, then _up_ four steps, then _left_ three steps, then _down_ one step, and so on.
+//! After each step, you'll need to update the position of the tail if the step means the head is no longer adjacent to the tail.
+//! Visually, these motions occur as follows (`s` marks the starting position as a reference point):
+//!
+//! ```text
+//! == Initial State ==
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! H.....  (H covers T, s)
+//!
+//! == R 4 ==
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! TH....  (T covers s)
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! sTH...
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! s.TH..
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! s..TH.
+//!
+//! == U 4 ==
+//!
+//! ......
+//! ......
+//! ......
+//! ....H.
+//! s..T..
+//!
+//! ......
+//! ......
+//! ....H.
+//! ....T.
+//! s.....
+//!
+//! ......
+//! ....H.
+//! ....T.
+//! ......
+//! s.....
+//!
+//! ....H.
+//! ....T.
+//! ......
+//! ......
+//! s.....
+//!
+//! == L 3 ==
+//!
+//! ...H..
+//! ....T.
+//! ......
+//! ......
+//! s.....
+//!
+//! ..HT..
+//! ......
+//! ......
+//! ......
+//! s.....
+//!
+//! .HT...
+//! ......
+//! ......
+//! ......
+//! s.....
+//!
+//! == D 1 ==
+//!
+//! ..T...
+//! .H....
+//! ......
+//! ......
+//! s.....
+//!
+//! == R 4 ==
+//!
+//! ..T...
+//! ..H...
+//! ......
+//! ......
+//! s.....
+//!
+//! ..T...
+//! ...H..
+//! ......
+//! ......
+//! s.....
+//!
+//! ......
+//! ...TH.
+//! ......
+//! ......
+//! s.....
+//!
+//! ......
+//! ....TH
+//! ......
+//! ......
+//! s.....
+//!
+//! == D 1 ==
+//!
+//! ......
+//! ....T.
+//! .....H
+//! ......
+//! s.....
+//!
+//! == L 5 ==
+//!
+//! ......
+//! ....T.
+//! ....H.
+//! ......
+//! s.....
+//!
+//! ......
+//! ....T.
+//! ...H..
+//! ......
+//! s.....
+//!
+//! ......
+//! ......
+//! ..HT..
+//! ......
+//! s.....
+//!
+//! ......
+//! ......
+//! .HT...
+//! ......
+//! s.....
+//!
+//! ......
+//! ......
+//! HT....
+//! ......
+//! s.....
+//!
+//! == R 2 ==
+//!
+//! ......
+//! ......
+//! .H....  (H covers T)
+//! ......
+//! s.....
+//!
+//! ......
+//! ......
+//! .TH...
+//! ......
+//! s.....
+//! ```
+//!
+//! After simulating the rope, you can count up all of the positions the _tail visited at least once_.
+//! In this diagram, `s` again marks the starting position (which the tail also visited) and `#` marks other positions the tail visited:
+//!
+//! ```text
+//! ..##..
+//! ...##.
+//! .####.
+//! ....#.
+//! s###..
+//! ```
+//!
+//! So, there are _`13`_ positions the tail visited at least once.
+//!
+//! Simulate your complete hypothetical series of motions.
+//! _How many positions does the tail of the rope visit at least once?_
+//!
+//! ## --- Part Two ---
+//!
+//! A rope snaps!
+//! Suddenly, the river is getting a lot closer than you remember.
+//! The bridge is still there, but some of the ropes that broke are now whipping toward you as you fall through the air!
+//!
+//! The ropes are moving too quickly to grab; you only have a few seconds to choose how to arch your body to avoid being hit.
+//! Fortunately, your simulation can be extended to support longer ropes.
+//!
+//! Rather than two knots, you now must simulate a rope consisting of _ten_ knots.
+//! One knot is still the head of the rope and moves according to the series of motions.
+//! Each knot further down the rope follows the knot in front of it using the same rules as before.
+//!
+//! Using the same series of motions as the above example, but with the knots marked `H`, `1`, `2`, ..., `9`, the motions now occur as follows:
+//!
+//! ```text
+//! == Initial State ==
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! H.....  (H covers 1, 2, 3, 4, 5, 6, 7, 8, 9, s)
+//!
+//! == R 4 ==
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! 4321H.  (4 covers 5, 6, 7, 8, 9, s)
+//!
+//! == U 4 ==
+//!
+//! ....H.
+//! ....1.
+//! ..432.
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == L 3 ==
+//!
+//! .H1...
+//! ...2..
+//! ..43..
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == D 1 ==
+//!
+//! ..1...
+//! .H.2..
+//! ..43..
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == R 4 ==
+//!
+//! ......
+//! ...21H
+//! ..43..
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == D 1 ==
+//!
+//! ......
+//! ...21.
+//! ..43.H
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == L 5 ==
+//!
+//! ......
+//! ......
+//! H123..  (2 covers 4)
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == R 2 ==
+//!
+//! ......
+//! ......
+//! .1H3..  (H covers 2, 4)
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//! ```
+//!
+//! Now, here's a larger example:
+//!
+//! ```text
+//! R 5
+//! U 8
+//! L 8
+//! D 3
+//! R 17
+//! D 10
+//! L 25
+//! U 20
+//! ```
+//!
+//! These motions occur as follows (individual steps are not shown):
+//!
+//! ```text
+//! == Initial State ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........H..............  (H covers 1, 2, 3, 4, 5, 6, 7, 8, 9, s)
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == R 5 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........54321H.........  (5 covers 6, 7, 8, 9, s)
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == U 8 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ................H.........
+//! ................1.........
+//! ................2.........
+//! ................3.........
+//! ...............54.........
+//! ..............6...........
+//! .............7............
+//! ............8.............
+//! ...........9..............  (9 covers s)
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == L 8 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ........H1234.............
+//! ............5.............
+//! ............6.............
+//! ............7.............
+//! ............8.............
+//! ............9.............
+//! ..........................
+//! ..........................
+//! ...........s..............
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == D 3 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! .........2345.............
+//! ........1...6.............
+//! ........H...7.............
+//! ............8.............
+//! ............9.............
+//! ..........................
+//! ..........................
+//! ...........s..............
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == R 17 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ................987654321H
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........s..............
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == D 10 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........s.........98765
+//! .........................4
+//! .........................3
+//! .........................2
+//! .........................1
+//! .........................H
+//!
+//! == L 25 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........s..............
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! H123456789................
+//!
+//! == U 20 ==
+//!
+//! H.........................
+//! 1.........................
+//! 2.........................
+//! 3.........................
+//! 4.........................
+//! 5.........................
+//! 6.........................
+//! 7.........................
+//! 8.........................
+//! 9.........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........s..............
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ```
+//!
+//! Now, the tail (`9`) visits _`36`_ positions (including `s`) at least once:
+//!
+//! ```text
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! #.........................
+//! #.............###.........
+//! #............#...#........
+//! .#..........#.....#.......
+//! ..#..........#.....#......
+//! ...#........#.......#.....
+//! ....#......s.........#....
+//! .....#..............#.....
+//! ......#............#......
+//! .......#..........#.......
+//! ........#........#........
+//! .........########.........
+//! ```
+//!
+//! Simulate your complete series of motions on a larger rope with ten knots.
+//! _How many positions does the tail of the rope visit at least once?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+type Pos = (i32, i32);
+
+#[derive(Debug, Copy, Clone)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn delta(&self) -> Pos {
+        match self {
+            Self::Up => (0, 1),
+            Self::Down => (0, -1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Self::Up),
+            "D" => Ok(Self::Down),
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(anyhow!("Invalid direction: {}", s)),
+        }
+    }
+}
+
+#[derive(Inpt, Debug)]
+#[inpt(regex = r"(.) (\d+)")]
+struct Motion {
+    #[inpt(from_str)]
+    direction: Direction,
+    steps: u32,
+}
+
+/// A rope made of any number of knots, recording every position the tail visited.
+#[derive(Debug, Clone)]
+struct Rope {
+    /// The head is the first knot, the tail the last one
+    knots: Vec<Pos>,
+    tail_visited: HashSet<Pos>,
+    /// Bounding box of all head positions, as `(min, max)` corners
+    bounds: (Pos, Pos),
+}
+
+impl Rope {
+    fn new(num_knots: usize) -> Self {
+        assert!(num_knots > 0, "A rope needs at least one knot");
+        Self {
+            knots: vec![(0, 0); num_knots],
+            tail_visited: HashSet::from([(0, 0)]),
+            bounds: ((0, 0), (0, 0)),
+        }
+    }
+
+    fn apply(&mut self, motion: &Motion) {
+        for _ in 0..motion.steps {
+            self.step(motion.direction);
+        }
+    }
+
+    /// Move the head a single step and let every other knot follow the knot in front of it.
+    fn step(&mut self, direction: Direction) {
+        let (dx, dy) = direction.delta();
+        self.knots[0].0 += dx;
+        self.knots[0].1 += dy;
+        let head = self.knots[0];
+        let (min, max) = &mut self.bounds;
+        *min = (min.0.min(head.0), min.1.min(head.1));
+        *max = (max.0.max(head.0), max.1.max(head.1));
+
+        for idx in 1..self.knots.len() {
+            let front = self.knots[idx - 1];
+            let knot = &mut self.knots[idx];
+            let (dx, dy) = (front.0 - knot.0, front.1 - knot.1);
+            if dx.abs() <= 1 && dy.abs() <= 1 {
+                // Touching, so none of the later knots move either
+                return;
+            }
+            knot.0 += dx.signum();
+            knot.1 += dy.signum();
+        }
+
+        self.tail_visited.insert(*self.knots.last().unwrap());
+    }
+
+    /// Render the positions the tail visited like the puzzle description.
+    ///
+    /// `s` marks the starting position, `#` every other visited position.
+    /// The tail never leaves the area the head moved in, so that is the area shown.
+    #[cfg(test)]
+    fn display_trail(&self) -> String {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds;
+        let mut s = String::new();
+        for y in (min_y..=max_y).rev() {
+            for x in min_x..=max_x {
+                s.push(if (x, y) == (0, 0) {
+                    's'
+                } else if self.tail_visited.contains(&(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+/// Run all motions on a rope with `num_knots` knots.
+fn simulate(motions: &[Motion], num_knots: usize) -> Rope {
+    let mut rope = Rope::new(num_knots);
+    for motion in motions {
+        rope.apply(motion);
+    }
+    rope
+}
+
+fn input_generator(input: &str) -> Result<Vec<Motion>> {
+    input
+        .lines()
+        .map(|line| inpt(line).map_err(|err| anyhow!("Invalid motion {line:?}: {err}")))
+        .collect()
+}
+
+fn part1(input: &[Motion]) -> usize {
+    simulate(input, 2).tail_visited.len()
+}
+
+fn part2(input: &[Motion]) -> usize {
+    simulate(input, 10).tail_visited.len()
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day09>();
+
+struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Motion>;
+
+    const DAY: u32 = 9;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_display_trail() -> Result<()> {
+    let values = input_generator(&example_input(9, Part::One, 3)?)?;
+    let expected = example_input(9, Part::One, 5)? + "\n";
+    assert_eq!(expected, simulate(&values, 2).display_trail());
+
+    let values = input_generator(&example_input(9, Part::Two, 1)?)?;
+    let expected = example_input(9, Part::Two, 3)? + "\n";
+    assert_eq!(expected, simulate(&values, 10).display_trail());
+    Ok(())
+}
+
+#[test]
+fn test_part2_first_example() -> Result<()> {
+    // Only the larger example of part two is checked against the docs by default
+    let values = input_generator(&example_input(9, Part::One, 3)?)?;
+    assert_eq!(1, part2(&values));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_motion() {
+    assert!(input_generator("R 4\nX 2").is_err());
+    assert!(input_generator("R four").is_err());
+}
@@ -0,0 +1,483 @@
+//! # Day 10: Cathode-Ray Tube
+//!
+//! ## --- Part One ---
+//!
+//! You avoid the ropes, plunge into the river, and swim to shore.
+//!
+//! The Elves yell something about meeting back up with them upriver, but the river is too loud to tell exactly what they're saying.
+//! They finish crossing the bridge and disappear from view.
+//!
+//! Situations like this must be why the Elves prioritized getting the communication system on your handheld device working.
+//! You pull it out of your pack, but the amount of water slowly draining from a big crack in its screen tells you it probably won't be of much immediate use.
+//!
+//! _Unless_, that is, you can design a replacement for the device's video system!
+//! It seems to be some kind of [cathode-ray tube](https://en.wikipedia.org/wiki/Cathode-ray_tube) screen and simple CPU that are both driven by a precise _clock circuit_.
+//! The clock circuit ticks at a constant rate; each tick is called a _cycle_.
+//!
+//! Start by figuring out the signal being sent by the CPU.
+//! The CPU has a single register, `X`, which starts with the value `1`.
+//! It supports only two instructions:
+//!
+//! * `addx V` takes _two cycles_ to complete.
+//!     _After_ two cycles, the `X` register is increased by the value `V`.
+//!     (`V` can be negative.)
+//! * `noop` takes _one cycle_ to complete.
+//!     It has no other effect.
+//!
+//! The CPU uses these instructions in a program (your puzzle input) to, somehow, tell you what to draw on the screen.
+//!
+//! Consider the following small program:
+//!
+//! ```text
+//! noop
+//! addx 3
+//! addx -5
+//! ```
+//!
+//! Execution of this program proceeds as follows:
+//!
+//! * At the start of the first cycle, the `noop` instruction begins execution.
+//!     During the first cycle, `X` is `1`.
+//!     After the first cycle, the `noop` instruction finishes execution, doing nothing.
+//! * At the start of the second cycle, the `addx 3` instruction begins execution.
+//!     During the second cycle, `X` is still `1`.
+//! * During the third cycle, `X` is still `1`.
+//!     After the third cycle, the `addx 3` instruction finishes execution, setting `X` to `4`.
+//! * At the start of the fourth cycle, the `addx -5` instruction begins execution.
+//!     During the fourth cycle, `X` is still `4`.
+//! * During the fifth cycle, `X` is still `4`.
+//!     After the fifth cycle, the `addx -5` instruction finishes execution, setting `X` to `-1`.
+//!
+//! Maybe you can learn something by looking at the value of the `X` register throughout execution.
+//! For now, consider the _signal strength_ (the cycle number multiplied by the value of the `X` register) _during_ the 20th cycle and every 40 cycles after that (that is, during the 20th, 60th, 100th, 140th, 180th, and 220th cycles).
+//!
+//! For example, consider this larger program:
+//!
+//! ```text
+//! addx 15
+//! addx -11
+//! addx 6
+//! addx -3
+//! addx 5
+//! addx -1
+//! addx -8
+//! addx 13
+//! addx 4
+//! noop
+//! addx -1
+//! addx 5
+//! addx -1
+//! addx 5
+//! addx -1
+//! addx 5
+//! addx -1
+//! addx 5
+//! addx -1
+//! addx -35
+//! addx 1
+//! addx 24
+//! addx -19
+//! addx 1
+//! addx 16
+//! addx -11
+//! noop
+//! noop
+//! addx 21
+//! addx -15
+//! noop
+//! noop
+//! addx -3
+//! addx 9
+//! addx 1
+//! addx -3
+//! addx 8
+//! addx 1
+//! addx 5
+//! noop
+//! noop
+//! noop
+//! noop
+//! noop
+//! addx -36
+//! noop
+//! addx 1
+//! addx 7
+//! noop
+//! noop
+//! noop
+//! addx 2
+//! addx 6
+//! noop
+//! noop
+//! noop
+//! noop
+//! noop
+//! addx 1
+//! noop
+//! noop
+//! addx 7
+//! addx 1
+//! noop
+//! addx -13
+//! addx 13
+//! addx 7
+//! noop
+//! addx 1
+//! addx -33
+//! noop
+//! noop
+//! noop
+//! addx 2
+//! noop
+//! noop
+//! noop
+//! addx 8
+//! noop
+//! addx -1
+//! addx 2
+//! addx 1
+//! noop
+//! addx 17
+//! addx -9
+//! addx 1
+//! addx 1
+//! addx -3
+//! addx 11
+//! noop
+//! noop
+//! addx 1
+//! noop
+//! addx 1
+//! noop
+//! noop
+//! addx -13
+//! addx -19
+//! addx 1
+//! addx 3
+//! addx 26
+//! addx -30
+//! addx 12
+//! addx -1
+//! addx 3
+//! addx 1
+//! noop
+//! noop
+//! noop
+//! addx -9
+//! addx 18
+//! addx 1
+//! addx 2
+//! noop
+//! noop
+//! addx 9
+//! noop
+//! noop
+//! noop
+//! addx -1
+//! addx 2
+//! addx -37
+//! addx 1
+//! addx 3
+//! noop
+//! addx 15
+//! addx -21
+//! addx 22
+//! addx -6
+//! addx 1
+//! noop
+//! addx 2
+//! addx 1
+//! noop
+//! addx -10
+//! noop
+//! noop
+//! addx 20
+//! addx 1
+//! addx 2
+//! addx 2
+//! addx -6
+//! addx -11
+//! noop
+//! noop
+//! noop
+//! ```
+//!
+//! The interesting signal strengths can be determined as follows:
+//!
+//! * During the 20th cycle, register `X` has the value `21`, so the signal strength is 20 * 21 = _420_.
+//!     (The 20th cycle occurs in the middle of the second `addx -1`, so the value of register `X` is the starting value, `1`, plus all of the other `addx` values up to that point: 1 + 15 - 11 + 6 - 3 + 5 - 1 - 8 + 13 + 4 = 21.)
+//! * During the 60th cycle, register `X` has the value `19`, so the signal strength is 60 * 19 = `1140`.
+//! * During the 100th cycle, register `X` has the value `18`, so the signal strength is 100 * 18 = `1800`.
+//! * During the 140th cycle, register `X` has the value `21`, so the signal strength is 140 * 21 = `2940`.
+//! * During the 180th cycle, register `X` has the value `16`, so the signal strength is 180 * 16 = `2880`.
+//! * During the 220th cycle, register `X` has the value `18`, so the signal strength is 220 * 18 = `3960`.
+//!
+//! The sum of these signal strengths is _`13140`_.
+//!
+//! Find the signal strength during the 20th, 60th, 100th, 140th, 180th, and 220th cycles.
+//! _What is the sum of these six signal strengths?_
+//!
+//! ## --- Part Two ---
+//!
+//! It seems like the `X` register controls the horizontal position of a [sprite](https://en.wikipedia.org/wiki/Sprite_(computer_graphics)).
+//! Specifically, the sprite is 3 pixels wide, and the `X` register sets the horizontal position of the _middle_ of that sprite.
+//! (In this system, there is no such thing as "vertical position": if the sprite's horizontal position puts its pixels where the CRT is currently drawing, then those pixels will be drawn.)
+//!
+//! You count the pixels on the CRT: 40 wide and 6 high.
+//! This CRT screen draws the top row of pixels left-to-right, then the row below that, and so on.
+//! The left-most pixel in each row is in position `0`, and the right-most pixel in each row is in position `39`.
+//!
+//! Like the CPU, the CRT is tied closely to the clock circuit: the CRT draws _a single pixel during each cycle_.
+//! Representing each pixel of the screen as a `#`, here are the cycles during which the first and last pixel in each row are drawn:
+//!
+//! ```text
+//! Cycle   1 -> ######################################## <- Cycle  40
+//! Cycle  41 -> ######################################## <- Cycle  80
+//! Cycle  81 -> ######################################## <- Cycle 120
+//! Cycle 121 -> ######################################## <- Cycle 160
+//! Cycle 161 -> ######################################## <- Cycle 200
+//! Cycle 201 -> ######################################## <- Cycle 240
+//! ```
+//!
+//! So, by [carefully](https://en.wikipedia.org/wiki/Racing_the_Beam) [timing](https://www.youtube.com/watch?v=sJFnWZH5FXc) the CPU instructions and the CRT drawing operations, you should be able to determine whether the sprite is visible the instant each pixel is drawn.
+//! If the sprite is positioned such that one of its three pixels is the pixel currently being drawn, the screen produces a _lit_ pixel (`#`); otherwise, the screen leaves the pixel _dark_ (`.`).
+//!
+//! Allowing the program to run to completion causes the CRT to produce the following image:
+//!
+//! ```text
+//! ##..##..##..##..##..##..##..##..##..##..
+//! ###...###...###...###...###...###...###.
+//! ####....####....####....####....####....
+//! #####.....#####.....#####.....#####.....
+//! ######......######......######......####
+//! #######.......#######.......#######.....
+//! ```
+//!
+//! Render the image given by your program.
+//! _What eight capital letters appear on your CRT?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+/// Width of a single glyph in the CRT font, excluding the spacing column
+const GLYPH_WIDTH: usize = 4;
+
+#[derive(Debug, Copy, Clone)]
+enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    fn cycles(&self) -> usize {
+        match self {
+            Self::Noop => 1,
+            Self::Addx(_) => 2,
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(' ') {
+            None if s == "noop" => Ok(Self::Noop),
+            Some(("addx", value)) => Ok(Self::Addx(value.parse()?)),
+            _ => Err(anyhow!("Invalid instruction: {}", s)),
+        }
+    }
+}
+
+/// Iterator over the value of the `X` register _during_ each cycle, starting with cycle 1.
+#[derive(Debug, Clone)]
+struct Cpu<'a> {
+    program: std::slice::Iter<'a, Instruction>,
+    x: i32,
+    /// Instruction currently executing and the number of cycles it still needs
+    current: Option<(Instruction, usize)>,
+}
+
+impl<'a> Cpu<'a> {
+    fn new(program: &'a [Instruction]) -> Self {
+        Self {
+            program: program.iter(),
+            x: 1,
+            current: None,
+        }
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (instr, remaining) = match self.current.take() {
+            Some(current) => current,
+            None => {
+                let instr = *self.program.next()?;
+                (instr, instr.cycles())
+            }
+        };
+
+        let during = self.x;
+        if remaining > 1 {
+            self.current = Some((instr, remaining - 1));
+        } else if let Instruction::Addx(value) = instr {
+            self.x += value;
+        }
+        Some(during)
+    }
+}
+
+/// Draw the CRT image, one `bool` per pixel with `true` meaning lit.
+fn render_crt(program: &[Instruction]) -> Vec<[bool; CRT_WIDTH]> {
+    let mut rows = vec![[false; CRT_WIDTH]; CRT_HEIGHT];
+    for (cycle, x) in Cpu::new(program).enumerate().take(CRT_WIDTH * CRT_HEIGHT) {
+        let (row, col) = (cycle / CRT_WIDTH, cycle % CRT_WIDTH);
+        rows[row][col] = (x - col as i32).abs() <= 1;
+    }
+    rows
+}
+
+/// Render the CRT image the way the puzzle description draws it.
+#[cfg(test)]
+fn display_crt(rows: &[[bool; CRT_WIDTH]]) -> String {
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|&lit| if lit { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+/// The capital letters of the AoC font which are known to appear in puzzle answers.
+///
+/// Each glyph is 4 pixels wide and 6 pixels high, written as the rows joined together.
+static FONT: &[(char, &str)] = &[
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#...#....#.#..#...#...#."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Decode the letters shown on the CRT.
+///
+/// Every letter occupies a 5 pixel wide column: 4 pixels of glyph and 1 pixel of spacing.
+fn ocr(rows: &[[bool; CRT_WIDTH]]) -> Result<String> {
+    (0..CRT_WIDTH / (GLYPH_WIDTH + 1))
+        .map(|idx| {
+            let start = idx * (GLYPH_WIDTH + 1);
+            let glyph: String = rows
+                .iter()
+                .flat_map(|row| &row[start..start + GLYPH_WIDTH])
+                .map(|&lit| if lit { '#' } else { '.' })
+                .collect();
+            FONT.iter()
+                .find(|(_, pattern)| *pattern == glyph)
+                .map(|&(c, _)| c)
+                .ok_or_else(|| anyhow!("Unknown glyph at letter {}: {glyph}", idx + 1))
+        })
+        .collect()
+}
+
+fn input_generator(input: &str) -> Result<Vec<Instruction>> {
+    input.lines().map(str::parse).collect()
+}
+
+fn part1(input: &[Instruction]) -> i32 {
+    Cpu::new(input)
+        .enumerate()
+        .map(|(idx, x)| (idx as i32 + 1, x))
+        .skip(19)
+        .step_by(40)
+        .take(6)
+        .map(|(cycle, x)| cycle * x)
+        .sum()
+}
+
+fn part2(input: &[Instruction]) -> Result<String> {
+    ocr(&render_crt(input))
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day10>();
+
+struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    const DAY: u32 = 10;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_cpu() -> Result<()> {
+    let values = input_generator(&example_input(10, Part::One, 0)?)?;
+    assert_eq!(vec![1, 1, 1, 4, 4], Cpu::new(&values).collect_vec());
+    Ok(())
+}
+
+#[test]
+fn test_render_crt() -> Result<()> {
+    let values = input_generator(&example_input(10, Part::One, 1)?)?;
+    let expected = example_input(10, Part::Two, 1)?;
+    assert_eq!(expected, display_crt(&render_crt(&values)));
+    // The example image does not contain any letters
+    assert!(part2(&values).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_ocr() -> Result<()> {
+    let image = "\
+###..####.#..#.####..##..###..####.####.
+#..#....#.#.#.....#.#..#.#..#.#....#....
+#..#...#..##.....#..#....#..#.###..###..
+###...#...#.#...#...#....###..#....#....
+#.#..#....#.#..#....#..#.#....#....#....
+#..#.####.#..#.####..##..#....####.#....";
+    let rows = image
+        .lines()
+        .map(|line| {
+            let mut row = [false; CRT_WIDTH];
+            line.chars()
+                .zip(row.iter_mut())
+                .for_each(|(c, pixel)| *pixel = c == '#');
+            row
+        })
+        .collect_vec();
+    assert_eq!("RZKZCPEF", ocr(&rows)?);
+    Ok(())
+}
@@ -0,0 +1,394 @@
+//! # Day 11: Monkey in the Middle
+//!
+//! ## --- Part One ---
+//!
+//! As you finally start making your way upriver, you realize your pack is much lighter than you remember.
+//! Just then, one of the items from your pack goes flying overhead.
+//! Monkeys are playing [Keep Away](https://en.wikipedia.org/wiki/Keep_away) with your missing things!
+//!
+//! To get your stuff back, you need to be able to predict where the monkeys will throw your items.
+//! After some careful observation, you realize the monkeys operate based on _how worried you are about each item_.
+//!
+//! You take some notes (your puzzle input) on the items each monkey currently has, how worried you are about those items, and how the monkey makes decisions based on your worry level.
+//! For example:
+//!
+//! ```text
+//! Monkey 0:
+//!   Starting items: 79, 98
+//!   Operation: new = old * 19
+//!   Test: divisible by 23
+//!     If true: throw to monkey 2
+//!     If false: throw to monkey 3
+//!
+//! Monkey 1:
+//!   Starting items: 54, 65, 75, 74
+//!   Operation: new = old + 6
+//!   Test: divisible by 19
+//!     If true: throw to monkey 2
+//!     If false: throw to monkey 0
+//!
+//! Monkey 2:
+//!   Starting items: 79, 60, 97
+//!   Operation: new = old * old
+//!   Test: divisible by 13
+//!     If true: throw to monkey 1
+//!     If false: throw to monkey 3
+//!
+//! Monkey 3:
+//!   Starting items: 74
+//!   Operation: new = old + 3
+//!   Test: divisible by 17
+//!     If true: throw to monkey 0
+//!     If false: throw to monkey 1
+//! ```
+//!
+//! Each monkey has several attributes:
+//!
+//! * `Starting items` lists your _worry level_ for each item the monkey is currently holding in the order they will be inspected.
+//! * `Operation` shows how your worry level changes as that monkey inspects an item.
+//!     (An operation like `new = old * 5` means that your worry level after the monkey inspected the item is five times whatever your worry level was before inspection.)
+//! * `Test` shows how the monkey uses your worry level to decide where to throw an item next.
+//!     * `If true` shows what happens with an item if the `Test` was true.
+//!     * `If false` shows what happens with an item if the `Test` was false.
+//!
+//! After each monkey inspects an item but before it tests your worry level, your relief that the monkey's inspection didn't damage the item causes your worry level to be _divided by three_ and rounded down to the nearest integer.
+//!
+//! The monkeys take turns inspecting and throwing items.
+//! On a single monkey's _turn_, it inspects and throws all of the items it is holding one at a time and in the order listed.
+//! Monkey `0` goes first, then monkey `1`, and so on until each monkey has had one turn.
+//! The process of each monkey taking a single turn is called a _round_.
+//!
+//! When a monkey throws an item to another monkey, the item goes on the _end_ of the recipient monkey's list.
+//! A monkey that starts a round with no items could end up inspecting and throwing many items by the time its turn comes around.
+//! If a monkey is holding no items at the start of its turn, its turn ends.
+//!
+//! After round 20, the monkeys are holding items with these worry levels:
+//!
+//! ```text
+//! Monkey 0: 10, 12, 14, 26, 34
+//! Monkey 1: 245, 93, 53, 199, 115
+//! Monkey 2:
+//! Monkey 3:
+//! ```
+//!
+//! Chasing all of the monkeys at once is impossible; you're going to have to focus on the _two most active_ monkeys if you want any hope of getting your stuff back.
+//! Count the _total number of times each monkey inspects items_ over 20 rounds:
+//!
+//! ```text
+//! Monkey 0 inspected items 101 times.
+//! Monkey 1 inspected items 95 times.
+//! Monkey 2 inspected items 7 times.
+//! Monkey 3 inspected items 105 times.
+//! ```
+//!
+//! In this example, the two most active monkeys inspected items 101 and 105 times.
+//! The level of _monkey business_ in this situation can be found by multiplying these together: _`10605`_.
+//!
+//! Figure out which monkeys to chase by counting how many items they inspect over 20 rounds.
+//! _What is the level of monkey business after 20 rounds of stuff-slinging simian shenanigans?_
+//!
+//! ## --- Part Two ---
+//!
+//! You're worried you might not ever get your items back.
+//! So worried, in fact, that your relief that a monkey's inspection didn't damage an item _no longer causes your worry level to be divided by three_.
+//!
+//! Unfortunately, that relief was all that was keeping your worry levels from reaching _ridiculous levels_.
+//! You'll need to _find another way to keep your worry levels manageable_.
+//!
+//! At this rate, you might be putting up with these monkeys for a _very long time_ - possibly _`10000` rounds_!
+//!
+//! With these new rules, you can still figure out the monkey business after 10000 rounds.
+//! Using the same example above:
+//!
+//! ```text
+//! == After round 10000 ==
+//! Monkey 0 inspected items 52166 times.
+//! Monkey 1 inspected items 47830 times.
+//! Monkey 2 inspected items 1938 times.
+//! Monkey 3 inspected items 52013 times.
+//! ```
+//!
+//! After 10000 rounds, the two most active monkeys inspected items 52166 and 52013 times.
+//! Multiplying these together, the level of _monkey business_ in this situation is now _`2713310158`_.
+//!
+//! Worry levels are no longer divided by three after each item is inspected; you'll need to find another way to keep your worry levels manageable.
+//! Starting again from the initial state in your puzzle input, _what is the level of monkey business after 10000 rounds?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Operand {
+    Old,
+    Const(u64),
+}
+
+impl Operand {
+    fn eval(&self, old: u64) -> u64 {
+        match *self {
+            Self::Old => old,
+            Self::Const(value) => value,
+        }
+    }
+}
+
+impl FromStr for Operand {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "old" => Ok(Self::Old),
+            _ => Ok(Self::Const(s.parse()?)),
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Old => write!(f, "old"),
+            Self::Const(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The expression computing the new worry level, like `new = old * 19`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Operation {
+    Add(Operand, Operand),
+    Mul(Operand, Operand),
+}
+
+impl Operation {
+    fn eval(&self, old: u64) -> u64 {
+        match self {
+            Self::Add(lhs, rhs) => lhs.eval(old) + rhs.eval(old),
+            Self::Mul(lhs, rhs) => lhs.eval(old) * rhs.eval(old),
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expr = s
+            .strip_prefix("new = ")
+            .ok_or_else(|| anyhow!("Invalid operation: {}", s))?;
+        match expr.split(' ').collect_vec()[..] {
+            [lhs, "+", rhs] => Ok(Self::Add(lhs.parse()?, rhs.parse()?)),
+            [lhs, "*", rhs] => Ok(Self::Mul(lhs.parse()?, rhs.parse()?)),
+            _ => Err(anyhow!("Invalid operation: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Add(lhs, rhs) => write!(f, "new = {lhs} + {rhs}"),
+            Self::Mul(lhs, rhs) => write!(f, "new = {lhs} * {rhs}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().map(str::trim);
+        let mut field = |prefix: &str| -> Result<&str> {
+            let line = lines
+                .next()
+                .ok_or_else(|| anyhow!("Missing line starting with {prefix:?}"))?;
+            line.strip_prefix(prefix)
+                .ok_or_else(|| anyhow!("Expected line starting with {prefix:?}, got {line:?}"))
+        };
+
+        field("Monkey ")?;
+        let items = field("Starting items: ")?;
+        let items = if items.is_empty() {
+            Vec::new()
+        } else {
+            items
+                .split(", ")
+                .map(str::parse)
+                .collect::<Result<_, _>>()?
+        };
+        let operation = field("Operation: ")?.parse()?;
+        let divisor = field("Test: divisible by ")?.parse()?;
+        if divisor == 0 {
+            return Err(anyhow!("Monkeys cannot test for divisibility by 0"));
+        }
+        let if_true = field("If true: throw to monkey ")?.parse()?;
+        let if_false = field("If false: throw to monkey ")?.parse()?;
+
+        Ok(Self {
+            items,
+            operation,
+            divisor,
+            if_true,
+            if_false,
+        })
+    }
+}
+
+fn input_generator(input: &str) -> Result<Vec<Monkey>> {
+    let monkeys: Vec<Monkey> = input.split("\n\n").map(str::parse).try_collect()?;
+    for (idx, monkey) in monkeys.iter().enumerate() {
+        for target in [monkey.if_true, monkey.if_false] {
+            if target >= monkeys.len() {
+                return Err(anyhow!("Monkey {idx} throws to unknown monkey {target}"));
+            }
+        }
+    }
+    Ok(monkeys)
+}
+
+/// Play `rounds` rounds of keep away and return the monkey business level.
+///
+/// Without relief, worry levels are kept modulo the LCM of all divisors.
+/// That preserves the outcome of every divisibility test while keeping the numbers small.
+/// Dividing by 3 does not commute with the modulo, but with relief the numbers stay small anyway.
+fn monkey_business(monkeys: &[Monkey], rounds: usize, relief: bool) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let modulus = monkeys
+        .iter()
+        .fold(1, |lcm, monkey| lcm / gcd(lcm, monkey.divisor) * monkey.divisor);
+    let mut inspections = vec![0u64; monkeys.len()];
+
+    for _ in 0..rounds {
+        for idx in 0..monkeys.len() {
+            let items = std::mem::take(&mut monkeys[idx].items);
+            inspections[idx] += items.len() as u64;
+            for item in items {
+                let monkey = &monkeys[idx];
+                let mut worry = monkey.operation.eval(item);
+                if relief {
+                    worry /= 3;
+                } else {
+                    worry %= modulus;
+                }
+                let target = if worry.is_multiple_of(monkey.divisor) {
+                    monkey.if_true
+                } else {
+                    monkey.if_false
+                };
+                monkeys[target].items.push(worry);
+            }
+        }
+    }
+
+    inspections.sort_unstable();
+    inspections.iter().rev().take(2).product()
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn part1(input: &[Monkey]) -> u64 {
+    monkey_business(input, 20, true)
+}
+
+fn part2(input: &[Monkey]) -> u64 {
+    monkey_business(input, 10_000, false)
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day11>();
+
+struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    const DAY: u32 = 11;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_parse_operation() -> Result<()> {
+    let values = input_generator(&example_input(11, Part::One, 0)?)?;
+    let operations = values.iter().map(|m| m.operation.to_string()).collect_vec();
+    assert_eq!(
+        vec![
+            "new = old * 19",
+            "new = old + 6",
+            "new = old * old",
+            "new = old + 3"
+        ],
+        operations
+    );
+    Ok(())
+}
+
+#[test]
+fn test_part1_above_modulus() -> Result<()> {
+    // The LCM is 210, but the worry levels reach 18067 within 20 rounds
+    let values = input_generator(
+        r"Monkey 0:
+  Starting items: 88, 95
+  Operation: new = old * old
+  Test: divisible by 2
+    If true: throw to monkey 3
+    If false: throw to monkey 2
+
+Monkey 1:
+  Starting items: 51, 87
+  Operation: new = old + 6
+  Test: divisible by 7
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 2:
+  Starting items: 73, 66
+  Operation: new = old * 7
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 69, 87
+  Operation: new = old + 2
+  Test: divisible by 5
+    If true: throw to monkey 2
+    If false: throw to monkey 1",
+    )?;
+    assert_eq!(24480, part1(&values));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_monkeys() -> Result<()> {
+    let example = example_input(11, Part::One, 0)?;
+    assert!(input_generator(&example.replace("divisible by 13", "divisible by 0")).is_err());
+    assert!(input_generator(&example.replace("throw to monkey 3", "throw to monkey 4")).is_err());
+    Ok(())
+}
@@ -0,0 +1,277 @@
+//! # Day 12: Hill Climbing Algorithm
+//!
+//! ## --- Part One ---
+//!
+//! You try contacting the Elves using your handheld device, but the river you're following must be too low to get a decent signal.
+//!
+//! You ask the device for a heightmap of the surrounding area (your puzzle input).
+//! The heightmap shows the local area from above broken into a grid; the elevation of each square of the grid is given by a single lowercase letter, where `a` is the lowest elevation, `b` is the next-lowest, and so on up to the highest elevation, `z`.
+//!
+//! Also included on the heightmap are marks for your current position (`S`) and the location that should get the best signal (`E`).
+//! Your current position (`S`) has elevation `a`, and the location that should get the best signal (`E`) has elevation `z`.
+//!
+//! You'd like to reach `E`, but to save energy, you should do it in _as few steps as possible_.
+//! During each step, you can move exactly one square up, down, left, or right.
+//! To avoid needing to get out your climbing gear, the elevation of the destination square can be _at most one higher_ than the elevation of your current square; that is, if your current elevation is `m`, you could step to elevation `n`, but not to elevation `o`.
+//! (This also means that the elevation of the destination square can be much lower than the elevation of your current square.)
+//!
+//! For example:
+//!
+//! ```text
+//! Sabqponm
+//! abcryxxl
+//! accszExk
+//! acctuvwj
+//! abdefghi
+//! ```
+//!
+//! Here, you start in the top-left corner; your goal is near the middle.
+//! You could start by moving down or right, but eventually you'll need to head toward the `e` at the bottom.
+//! From there, you can spiral around to the goal:
+//!
+//! ```text
+//! v..v<<<<
+//! >v.vv<<^
+//! .>vv>E^^
+//! ..v>>>^^
+//! ..>>>>>^
+//! ```
+//!
+//! In the above diagram, the symbols indicate whether the path exits each square moving up (`^`), down (`v`), left (`<`), or right (`>`).
+//! The location that should get the best signal is still `E`, and `.` marks unvisited squares.
+//!
+//! This path reaches the goal in _`31`_ steps, the fewest possible.
+//!
+//! _What is the fewest steps required to move from your current position to the location that should get the best signal?_
+//!
+//! ## --- Part Two ---
+//!
+//! As you walk up the hill, you suspect that the Elves will want to turn this into a hiking trail.
+//! The beginning isn't very scenic, though; perhaps you can find a better starting point.
+//!
+//! To maximize exercise while hiking, the trail should start as low as possible: elevation `a`.
+//! The goal is still the square marked `E`.
+//! However, the trail should still be direct, taking the fewest steps to reach its goal.
+//! So, you'll need to find the shortest path from _any square at elevation `a`_ to the square marked `E`.
+//!
+//! Again consider the example from above:
+//!
+//! ```text
+//! Sabqponm
+//! abcryxxl
+//! accszExk
+//! acctuvwj
+//! abdefghi
+//! ```
+//!
+//! Now, there are six choices for starting position (five marked `a`, plus the square marked `S` that counts as being at elevation `a`).
+//! If you start at the bottom-left square, you can reach the goal most quickly:
+//!
+//! ```text
+//! ...v<<<<
+//! ...vv<<^
+//! ...v>E^^
+//! .>v>>>^^
+//! >^>>>>>^
+//! ```
+//!
+//! This path reaches the goal in only _`29`_ steps, the fewest possible.
+//!
+//! _What is the fewest steps required to move starting from any square with elevation `a` to the location that should get the best signal?_
+
+use crate::grid::Grid;
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+use std::collections::VecDeque;
+
+type Pos = (usize, usize);
+
+#[derive(Debug, Clone)]
+struct Heightmap {
+    /// Elevation of each square, `0` for `a` up to `25` for `z`
+    heights: Grid<u8>,
+    start: Pos,
+    end: Pos,
+}
+
+impl Heightmap {
+    /// Whether a single step from `from` to `to` is allowed
+    fn can_step(&self, from: Pos, to: Pos) -> bool {
+        self.heights[to] <= self.heights[from] + 1
+    }
+
+    /// Breadth-first search backwards from `E`.
+    ///
+    /// The result holds the fewest steps from every square to `E`, or `None` if `E` cannot be reached.
+    fn distances_to_end(&self) -> Grid<Option<usize>> {
+        let mut distances = Grid::new(self.heights.width(), self.heights.height(), None);
+        let mut queue = VecDeque::from([self.end]);
+        distances[self.end] = Some(0);
+
+        while let Some(pos) = queue.pop_front() {
+            let dist = distances[pos].unwrap();
+            for prev in self.heights.neighbors(pos) {
+                if distances[prev].is_none() && self.can_step(prev, pos) {
+                    distances[prev] = Some(dist + 1);
+                    queue.push_back(prev);
+                }
+            }
+        }
+        distances
+    }
+
+    /// One shortest path from `from` to `E`, including both endpoints.
+    #[cfg(test)]
+    fn shortest_path(&self, distances: &Grid<Option<usize>>, from: Pos) -> Option<Vec<Pos>> {
+        let mut path = vec![from];
+        let mut pos = from;
+        let mut dist = distances[from]?;
+        while dist > 0 {
+            pos = self
+                .heights
+                .neighbors(pos)
+                .find(|&next| distances[next] == Some(dist - 1) && self.can_step(pos, next))
+                .expect("BFS distances always lead towards E");
+            path.push(pos);
+            dist -= 1;
+        }
+        Some(path)
+    }
+
+    /// Render a path as the arrow map from the puzzle description.
+    #[cfg(test)]
+    fn display_path(&self, path: &[Pos]) -> String {
+        let mut map = Grid::new(self.heights.width(), self.heights.height(), '.');
+        for (&(x0, y0), &(x1, y1)) in path.iter().tuple_windows() {
+            map[(x0, y0)] = match (x1 as isize - x0 as isize, y1 as isize - y0 as isize) {
+                (1, 0) => '>',
+                (-1, 0) => '<',
+                (0, 1) => 'v',
+                (0, -1) => '^',
+                _ => unreachable!("path steps are always orthogonal"),
+            };
+        }
+        map[self.end] = 'E';
+        map.display(|&c| c)
+    }
+}
+
+fn input_generator(input: &str) -> Result<Heightmap> {
+    let chars = Grid::parse(input, |c| match c {
+        'a'..='z' | 'S' | 'E' => Ok(c),
+        _ => Err(anyhow!("Invalid square: {c}")),
+    })?;
+    let start = chars
+        .position(|&c| c == 'S')
+        .ok_or_else(|| anyhow!("Missing start square S"))?;
+    let end = chars
+        .position(|&c| c == 'E')
+        .ok_or_else(|| anyhow!("Missing end square E"))?;
+    let heights = chars.map(|_, &c| match c {
+        'S' => 0,
+        'E' => b'z' - b'a',
+        _ => c as u8 - b'a',
+    });
+    Ok(Heightmap {
+        heights,
+        start,
+        end,
+    })
+}
+
+fn part1(input: &Heightmap) -> Result<usize> {
+    input.distances_to_end()[input.start].ok_or_else(|| anyhow!("E is not reachable from S"))
+}
+
+fn part2(input: &Heightmap) -> Result<usize> {
+    // A single BFS from E answers the question for all squares at once
+    let distances = input.distances_to_end();
+    input
+        .heights
+        .iter()
+        .filter(|(_, &height)| height == 0)
+        .filter_map(|(pos, _)| distances[pos])
+        .min()
+        .ok_or_else(|| anyhow!("E is not reachable from any square at elevation a"))
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day12>();
+
+struct Day12;
+
+impl Solution for Day12 {
+    type Input = Heightmap;
+
+    const DAY: u32 = 12;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+/// Follow the arrows of a map from the puzzle description, starting at `from`.
+#[cfg(test)]
+fn follow_arrows(map: &str, from: Pos) -> Result<Vec<Pos>> {
+    let map = Grid::parse(map, Ok)?;
+    let mut path = vec![from];
+    let (mut x, mut y) = from;
+    loop {
+        match map[(x, y)] {
+            '>' => x += 1,
+            '<' => x -= 1,
+            'v' => y += 1,
+            '^' => y -= 1,
+            'E' => return Ok(path),
+            c => return Err(anyhow!("Path leaves the arrows at {:?} on {c:?}", (x, y))),
+        }
+        path.push((x, y));
+    }
+}
+
+#[test]
+fn test_display_path() -> Result<()> {
+    let values = input_generator(&example_input(12, Part::One, 0)?)?;
+    let distances = values.distances_to_end();
+    let bottom_left = (0, values.heights.height() - 1);
+    for (part, from, steps) in [(Part::One, values.start, 31), (Part::Two, bottom_left, 29)] {
+        let expected = example_input(12, part, 1)?;
+        // Several shortest paths exist, the description shows one of them
+        let path = follow_arrows(&expected, from)?;
+        assert_eq!(steps + 1, path.len());
+        assert!(path
+            .iter()
+            .tuple_windows()
+            .all(|(&a, &b)| values.can_step(a, b)));
+        assert_eq!(expected + "\n", values.display_path(&path));
+
+        let path = values
+            .shortest_path(&distances, from)
+            .ok_or_else(|| anyhow!("no path"))?;
+        assert_eq!(steps + 1, path.len());
+        assert_eq!(Some(&values.end), path.last());
+        assert!(path
+            .iter()
+            .tuple_windows()
+            .all(|(&a, &b)| values.can_step(a, b)));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_unreachable() -> Result<()> {
+    let values = input_generator("SazE")?;
+    assert!(part1(&values).is_err());
+    assert!(part2(&values).is_err());
+    Ok(())
+}
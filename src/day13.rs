@@ -0,0 +1,397 @@
+//! # Day 13: Distress Signal
+//!
+//! ## --- Part One ---
+//!
+//! You climb the hill and again try contacting the Elves.
+//! However, you instead receive a signal you weren't expecting: a _distress signal_.
+//!
+//! Your handheld device must still not be working properly; the packets from the distress signal got decoded _out of order_.
+//! You'll need to re-order the list of received packets (your puzzle input) to decode the message.
+//!
+//! Your list consists of pairs of packets; pairs are separated by a blank line.
+//! You need to identify _how many pairs of packets are in the right order_.
+//!
+//! For example:
+//!
+//! ```text
+//! [1,1,3,1,1]
+//! [1,1,5,1,1]
+//!
+//! [[1],[2,3,4]]
+//! [[1],4]
+//!
+//! [9]
+//! [[8,7,6]]
+//!
+//! [[4,4],4,4]
+//! [[4,4],4,4,4]
+//!
+//! [7,7,7,7]
+//! [7,7,7]
+//!
+//! []
+//! [3]
+//!
+//! [[[]]]
+//! [[]]
+//!
+//! [1,[2,[3,[4,[5,6,7]]]],8,9]
+//! [1,[2,[3,[4,[5,6,0]]]],8,9]
+//! ```
+//!
+//! Packet data consists of lists and integers.
+//! Each list starts with `[`, ends with `]`, and contains zero or more comma-separated values (either integers or other lists).
+//! Each packet is always a list and appears on its own line.
+//!
+//! When comparing two values, the first value is called _left_ and the second value is called _right_.
+//! Then:
+//!
+//! * If _both values are integers_, the _lower integer_ should come first.
+//!     If the left integer is lower than the right integer, the inputs are in the right order.
+//!     If the left integer is higher than the right integer, the inputs are not in the right order.
+//!     Otherwise, the inputs are the same integer; continue checking the next part of the input.
+//! * If _both values are lists_, compare the first value of each list, then the second value, and so on.
+//!     If the left list runs out of items first, the inputs are in the right order.
+//!     If the right list runs out of items first, the inputs are not in the right order.
+//!     If the lists are the same length and no comparison makes a decision about the order, continue checking the next part of the input.
+//! * If _exactly one value is an integer_, convert the integer to a list which contains that integer as its only value, then retry the comparison.
+//!     For example, if comparing `[0,0,0]` and `2`, convert the right value to `[2]` (a list containing `2`); the result is then found by instead comparing `[0,0,0]` and `[2]`.
+//!
+//! Using these rules, you can determine which of the pairs in the example are in the right order:
+//!
+//! ```text
+//! == Pair 1 ==
+//! - Compare [1,1,3,1,1] vs [1,1,5,1,1]
+//!   - Compare 1 vs 1
+//!   - Compare 1 vs 1
+//!   - Compare 3 vs 5
+//!     - Left side is smaller, so inputs are in the right order
+//!
+//! == Pair 2 ==
+//! - Compare [[1],[2,3,4]] vs [[1],4]
+//!   - Compare [1] vs [1]
+//!     - Compare 1 vs 1
+//!   - Compare [2,3,4] vs 4
+//!     - Mixed types; convert right to [4] and retry comparison
+//!     - Compare [2,3,4] vs [4]
+//!       - Compare 2 vs 4
+//!         - Left side is smaller, so inputs are in the right order
+//!
+//! == Pair 3 ==
+//! - Compare [9] vs [[8,7,6]]
+//!   - Compare 9 vs [8,7,6]
+//!     - Mixed types; convert left to [9] and retry comparison
+//!     - Compare [9] vs [8,7,6]
+//!       - Compare 9 vs 8
+//!         - Right side is smaller, so inputs are not in the right order
+//!
+//! == Pair 4 ==
+//! - Compare [[4,4],4,4] vs [[4,4],4,4,4]
+//!   - Compare [4,4] vs [4,4]
+//!     - Compare 4 vs 4
+//!     - Compare 4 vs 4
+//!   - Compare 4 vs 4
+//!   - Compare 4 vs 4
+//!   - Left side ran out of items, so inputs are in the right order
+//!
+//! == Pair 5 ==
+//! - Compare [7,7,7,7] vs [7,7,7]
+//!   - Compare 7 vs 7
+//!   - Compare 7 vs 7
+//!   - Compare 7 vs 7
+//!   - Right side ran out of items, so inputs are not in the right order
+//!
+//! == Pair 6 ==
+//! - Compare [] vs [3]
+//!   - Left side ran out of items, so inputs are in the right order
+//!
+//! == Pair 7 ==
+//! - Compare [[[]]] vs [[]]
+//!   - Compare [[]] vs []
+//!     - Right side ran out of items, so inputs are not in the right order
+//!
+//! == Pair 8 ==
+//! - Compare [1,[2,[3,[4,[5,6,7]]]],8,9] vs [1,[2,[3,[4,[5,6,0]]]],8,9]
+//!   - Compare 1 vs 1
+//!   - Compare [2,[3,[4,[5,6,7]]]] vs [2,[3,[4,[5,6,0]]]]
+//!     - Compare 2 vs 2
+//!     - Compare [3,[4,[5,6,7]]] vs [3,[4,[5,6,0]]]
+//!       - Compare 3 vs 3
+//!       - Compare [4,[5,6,7]] vs [4,[5,6,0]]
+//!         - Compare 4 vs 4
+//!         - Compare [5,6,7] vs [5,6,0]
+//!           - Compare 5 vs 5
+//!           - Compare 6 vs 6
+//!           - Compare 7 vs 0
+//!             - Right side is smaller, so inputs are not in the right order
+//! ```
+//!
+//! What are the indices of the pairs that are already _in the right order_?
+//! (The first pair has index 1, the second pair has index 2, and so on.)
+//! In the above example, the pairs in the right order are 1, 2, 4, and 6; the sum of these indices is _`13`_.
+//!
+//! Determine which pairs of packets are already in the right order.
+//! _What is the sum of the indices of those pairs?_
+//!
+//! ## --- Part Two ---
+//!
+//! Now, you just need to put _all_ of the packets in the right order.
+//! Disregard the blank lines in your list of received packets.
+//!
+//! The distress signal protocol also requires that you include two additional _divider packets_:
+//!
+//! ```text
+//! [[2]]
+//! [[6]]
+//! ```
+//!
+//! Using the same rules as before, organize all packets - the ones in your list of received packets as well as the two divider packets - into the correct order.
+//!
+//! For the example above, the result of putting the packets in the correct order is:
+//!
+//! ```text
+//! []
+//! [[]]
+//! [[[]]]
+//! [1,1,3,1,1]
+//! [1,1,5,1,1]
+//! [[1],[2,3,4]]
+//! [1,[2,[3,[4,[5,6,0]]]],8,9]
+//! [1,[2,[3,[4,[5,6,7]]]],8,9]
+//! [[1],4]
+//! [[2]]
+//! [3]
+//! [[4,4],4,4]
+//! [[4,4],4,4,4]
+//! [[6]]
+//! [7,7,7]
+//! [7,7,7,7]
+//! [[8,7,6]]
+//! [9]
+//! ```
+//!
+//! Afterward, locate the divider packets.
+//! To find the _decoder key_ for this distress signal, you need to determine the indices of the two divider packets and multiply them together.
+//! (The first packet is at index 1, the second packet is at index 2, and so on.)
+//! In this example, the divider packets are _10th_ and _14th_, and so the decoder key is _`140`_.
+//!
+//! Organize all of the packets into the correct order.
+//! _What is the decoder key for the distress signal?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A packet value, either an integer or a list of further values.
+///
+/// The untagged representation matches the packet syntax, so packets serialize to the same JSON text they are parsed from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Integer(left), Self::Integer(right)) => left.cmp(right),
+            // Lexicographic comparison of the lists is exactly what the puzzle asks for
+            (Self::List(left), Self::List(right)) => left.cmp(right),
+            (Self::Integer(_), Self::List(right)) => std::slice::from_ref(self).cmp(right),
+            (Self::List(left), Self::Integer(_)) => left[..].cmp(std::slice::from_ref(other)),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Equality follows the comparison rules, so `[[1]]` and `[1]` are equal.
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl FromStr for Packet {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = PacketParser {
+            input: s.as_bytes(),
+            pos: 0,
+        };
+        let packet = parser.value()?;
+        if parser.pos != s.len() {
+            return Err(anyhow!(
+                "Unexpected trailing data at position {}: {}",
+                parser.pos,
+                &s[parser.pos..]
+            ));
+        }
+        Ok(packet)
+    }
+}
+
+/// Recursive descent parser for the bracket syntax
+struct PacketParser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl PacketParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<()> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(c) => Err(anyhow!(
+                "Expected {:?} at position {}, found {:?}",
+                expected as char,
+                self.pos,
+                c as char
+            )),
+            None => Err(anyhow!("Expected {:?}, found end of input", expected as char)),
+        }
+    }
+
+    fn value(&mut self) -> Result<Packet> {
+        match self.peek() {
+            Some(b'[') => self.list(),
+            Some(c) if c.is_ascii_digit() => self.integer(),
+            Some(c) => Err(anyhow!(
+                "Unexpected {:?} at position {}",
+                c as char,
+                self.pos
+            )),
+            None => Err(anyhow!("Unexpected end of input")),
+        }
+    }
+
+    fn list(&mut self) -> Result<Packet> {
+        self.expect(b'[')?;
+        let mut values = Vec::new();
+        if self.peek() != Some(b']') {
+            values.push(self.value()?);
+            while self.peek() == Some(b',') {
+                self.pos += 1;
+                values.push(self.value()?);
+            }
+        }
+        self.expect(b']')?;
+        Ok(Packet::List(values))
+    }
+
+    fn integer(&mut self) -> Result<Packet> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let digits = std::str::from_utf8(&self.input[start..self.pos])?;
+        Ok(Packet::Integer(digits.parse()?))
+    }
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::List(values) => write!(f, "[{}]", values.iter().join(",")),
+        }
+    }
+}
+
+fn input_generator(input: &str) -> Result<Vec<(Packet, Packet)>> {
+    input
+        .split("\n\n")
+        .map(|pair| {
+            let (left, right) = pair
+                .split_once('\n')
+                .ok_or_else(|| anyhow!("Expected a pair of packets: {pair}"))?;
+            Ok((left.parse()?, right.parse()?))
+        })
+        .collect()
+}
+
+fn part1(input: &[(Packet, Packet)]) -> usize {
+    input
+        .iter()
+        .positions(|(left, right)| left < right)
+        .map(|idx| idx + 1)
+        .sum()
+}
+
+fn part2(input: &[(Packet, Packet)]) -> usize {
+    let divider = |value| Packet::List(vec![Packet::List(vec![Packet::Integer(value)])]);
+    let (first, second) = (divider(2), divider(6));
+
+    // The position of a divider in the sorted list is one more than the number of packets sorting before it.
+    // Counting avoids sorting all the packets.
+    let packets = input.iter().flat_map(|(left, right)| [left, right]);
+    let first_idx = packets.clone().filter(|&p| *p < first).count() + 1;
+    // The first divider also sorts before the second one
+    let second_idx = packets.filter(|&p| *p < second).count() + 2;
+    first_idx * second_idx
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day13>();
+
+struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Packet, Packet)>;
+
+    const DAY: u32 = 13;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_parse_display() -> Result<()> {
+    let input = example_input(13, Part::One, 0)?;
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        assert_eq!(line, line.parse::<Packet>()?.to_string());
+    }
+    assert!("[1,2".parse::<Packet>().is_err());
+    assert!("[1,,2]".parse::<Packet>().is_err());
+    assert!("[1]]".parse::<Packet>().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_serde_roundtrip() -> Result<()> {
+    let input = example_input(13, Part::One, 0)?;
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        let packet: Packet = line.parse()?;
+        let json = serde_json::to_string(&packet)?;
+        assert_eq!(line, json);
+        let deserialized: Packet = serde_json::from_str(&json)?;
+        assert_eq!(packet.to_string(), deserialized.to_string());
+    }
+    Ok(())
+}
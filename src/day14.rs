@@ -0,0 +1,442 @@
+//! # Day 14: Regolith Reservoir
+//!
+//! ## --- Part One ---
+//!
+//! The distress signal leads you to a giant waterfall!
+//! Actually, hang on - the signal seems like it's coming from the waterfall itself, and that doesn't make any sense.
+//! However, you do notice a little path that leads _behind_ the waterfall.
+//!
+//! Correction: the distress signal leads you behind a giant waterfall!
+//! There seems to be a large cave system here, and the signal definitely leads further inside.
+//!
+//! As you begin to make your way deeper underground, you feel the ground rumble for a moment.
+//! Sand begins pouring into the cave!
+//! If you don't quickly figure out where the sand is going, you could quickly become trapped!
+//!
+//! Fortunately, your [familiarity](https://adventofcode.com/2018/day/17) with analyzing the path of falling material will come in handy here.
+//! You scan a two-dimensional vertical slice of the cave above you (your puzzle input) and discover that it is mostly _air_ with structures made of _rock_.
+//!
+//! Your scan traces the path of each solid rock structure and reports the `x,y` coordinates that form the shape of the path, where `x` represents distance to the right and `y` represents distance down.
+//! Each path appears as a single line of text in your scan.
+//! After the first point of each path, each point indicates the end of a straight horizontal or vertical line to be drawn from the previous point.
+//! For example:
+//!
+//! ```text
+//! 498,4 -> 498,6 -> 496,6
+//! 503,4 -> 502,4 -> 502,9 -> 494,9
+//! ```
+//!
+//! This scan means that there are two paths of rock; the first path consists of two straight lines, and the second path consists of three straight lines.
+//! (Specifically, the first path consists of a line of rock from `498,4` through `498,6` and another line of rock from `498,6` through `496,6`.)
+//!
+//! The sand is pouring into the cave from point `500,0`.
+//!
+//! Drawing rock as `#`, air as `.`, and the source of the sand as `+`, this becomes:
+//!
+//! ```text
+//!   4     5  5
+//!   9     0  0
+//!   4     0  3
+//! 0 ......+...
+//! 1 ..........
+//! 2 ..........
+//! 3 ..........
+//! 4 ....#...##
+//! 5 ....#...#.
+//! 6 ..###...#.
+//! 7 ........#.
+//! 8 ........#.
+//! 9 #########.
+//! ```
+//!
+//! Sand is produced _one unit at a time_, and the next unit of sand is not produced until the previous unit of sand _comes to rest_.
+//! A unit of sand is large enough to fill one tile of air in your scan.
+//!
+//! A unit of sand always falls _down one step_ if possible.
+//! If the tile immediately below is blocked (by rock or sand), the unit of sand attempts to instead move diagonally _one step down and to the left_.
+//! If that tile is blocked, the unit of sand attempts to instead move diagonally _one step down and to the right_.
+//! Sand keeps moving as long as it is able to do so, at each step trying to move down, then down-left, then down-right.
+//! If all three possible destinations are blocked, the unit of sand _comes to rest_ and no longer moves, at which point the next unit of sand is created back at the source.
+//!
+//! So, drawing sand that has come to rest as `o`, the first unit of sand simply falls straight down and then stops:
+//!
+//! ```text
+//! ......+...
+//! ..........
+//! ..........
+//! ..........
+//! ....#...##
+//! ....#...#.
+//! ..###...#.
+//! ........#.
+//! ......o.#.
+//! #########.
+//! ```
+//!
+//! The second unit of sand then falls straight down, lands on the first one, and then comes to rest to its left:
+//!
+//! ```text
+//! ......+...
+//! ..........
+//! ..........
+//! ..........
+//! ....#...##
+//! ....#...#.
+//! ..###...#.
+//! ........#.
+//! .....oo.#.
+//! #########.
+//! ```
+//!
+//! After a total of five units of sand have come to rest, they form this pattern:
+//!
+//! ```text
+//! ......+...
+//! ..........
+//! ..........
+//! ..........
+//! ....#...##
+//! ....#...#.
+//! ..###...#.
+//! ......o.#.
+//! ....oooo#.
+//! #########.
+//! ```
+//!
+//! After a total of 22 units of sand:
+//!
+//! ```text
+//! ......+...
+//! ..........
+//! ......o...
+//! .....ooo..
+//! ....#ooo##
+//! ....#ooo#.
+//! ..###ooo#.
+//! ....oooo#.
+//! ...ooooo#.
+//! #########.
+//! ```
+//!
+//! Finally, only two more units of sand can possibly come to rest:
+//!
+//! ```text
+//! ......+...
+//! ..........
+//! ......o...
+//! .....ooo..
+//! ....#ooo##
+//! ...o#ooo#.
+//! ..###ooo#.
+//! ....oooo#.
+//! .o.ooooo#.
+//! #########.
+//! ```
+//!
+//! Once all _`24`_ units of sand shown above have come to rest, all further sand flows out the bottom, falling into the endless void.
+//! Just for fun, the path any new sand takes before falling forever is shown here with `~`:
+//!
+//! ```text
+//! .......+...
+//! .......~...
+//! ......~o...
+//! .....~ooo..
+//! ....~#ooo##
+//! ...~o#ooo#.
+//! ..~###ooo#.
+//! ..~..oooo#.
+//! .~o.ooooo#.
+//! ~#########.
+//! ~..........
+//! ~..........
+//! ~..........
+//! ```
+//!
+//! Using your scan, simulate the falling sand.
+//! _How many units of sand come to rest before sand starts flowing into the abyss below?_
+//!
+//! ## --- Part Two ---
+//!
+//! You realize you misread the scan.
+//! There isn't an [endless void](https://www.youtube.com/watch?v=e5byK_ywMH8) at the bottom of the scan - there's floor, and you're standing on it!
+//!
+//! You don't have time to scan the floor, so assume the floor is an infinite horizontal line with a `y` coordinate equal to _two plus the highest `y` coordinate_ of any point in your scan.
+//!
+//! In the example above, the highest `y` coordinate of any point is `9`, and so the floor is at `y=11`.
+//! (This is as if your scan contained one extra rock path like `-infinity,11 -> infinity,11`.)
+//! With the added floor, the example above now looks like this:
+//!
+//! ```text
+//!         ...........+........
+//!         ....................
+//!         ....................
+//!         ....................
+//!         .........#...##.....
+//!         .........#...#......
+//!         .......###...#......
+//!         .............#......
+//!         .............#......
+//!         .....#########......
+//!         ....................
+//! <-- etc #################### etc -->
+//! ```
+//!
+//! To find somewhere safe to stand, you'll need to simulate falling sand until a unit of sand comes to rest at `500,0`, blocking the source entirely and stopping the flow of sand into the cave.
+//! In the example above, the situation finally looks like this after _`93`_ units of sand come to rest:
+//!
+//! ```text
+//! ............o............
+//! ...........ooo...........
+//! ..........ooooo..........
+//! .........ooooooo.........
+//! ........oo#ooo##o........
+//! .......ooo#ooo#ooo.......
+//! ......oo###ooo#oooo......
+//! .....oooo.oooo#ooooo.....
+//! ....oooooooooo#oooooo....
+//! ...ooo#########ooooooo...
+//! ..ooooo.......ooooooooo..
+//! #########################
+//! ```
+//!
+//! Using your scan, simulate the falling sand until the source of the sand becomes blocked.
+//! _How many units of sand come to rest?_
+
+use crate::grid::Grid;
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+/// Position `(x, y)` in cave coordinates
+type Pos = (usize, usize);
+
+const SAND_SOURCE: Pos = (500, 0);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+/// A slice of the cave, wide enough that sand piling up on the floor never leaves it.
+///
+/// Positions are grid positions, the scan is shifted so that the leftmost column is air.
+#[derive(Debug, Clone)]
+struct Cave {
+    tiles: Grid<Tile>,
+    /// Position of the sand source in the grid
+    source: Pos,
+    /// Highest `y` coordinate of any rock
+    max_rock_y: usize,
+}
+
+impl Cave {
+    fn floor_y(&self) -> usize {
+        self.max_rock_y + 2
+    }
+
+    fn tile(&self, pos: Pos) -> Tile {
+        self.tiles[pos]
+    }
+
+    fn set_tile(&mut self, pos: Pos, tile: Tile) {
+        self.tiles[pos] = tile;
+    }
+
+    /// Pour sand until it either falls into the abyss or blocks the source.
+    ///
+    /// With `floor` set, the infinite floor two below the lowest rock stops the sand.
+    /// Returns the number of units of sand which came to rest.
+    ///
+    /// The path of the previous grain is kept on a stack.
+    /// Every grain follows the same path as the one before until the spot where the previous one came to rest, so the next grain starts one step up on that path instead of at the source.
+    fn pour(&mut self, floor: bool) -> usize {
+        let floor_y = self.floor_y();
+        let mut path = vec![self.source];
+        let mut resting = 0;
+
+        while let Some(&(x, y)) = path.last() {
+            if !floor && y >= self.max_rock_y {
+                // Nothing below can stop the sand anymore
+                break;
+            }
+
+            let next = [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)]
+                .into_iter()
+                .find(|&pos| pos.1 < floor_y && self.tile(pos) == Tile::Air);
+            match next {
+                Some(pos) => path.push(pos),
+                None => {
+                    self.set_tile((x, y), Tile::Sand);
+                    resting += 1;
+                    path.pop();
+                }
+            }
+        }
+        resting
+    }
+
+    /// Render the cave like the puzzle description, cropped to the area containing rock or sand.
+    #[allow(dead_code)]
+    fn display(&self) -> String {
+        let used = self
+            .tiles
+            .iter()
+            .filter(|(_, &tile)| tile != Tile::Air)
+            .map(|(pos, _)| pos)
+            .collect_vec();
+        let (min_x, max_x) = used
+            .iter()
+            .map(|&(x, _)| x)
+            .chain([self.source.0])
+            .minmax()
+            .into_option()
+            .unwrap();
+        let max_y = used.iter().map(|&(_, y)| y).max().unwrap_or(0);
+
+        let mut s = String::new();
+        for y in 0..=max_y {
+            for x in min_x..=max_x {
+                s.push(match self.tile((x, y)) {
+                    _ if (x, y) == self.source => '+',
+                    Tile::Air => '.',
+                    Tile::Rock => '#',
+                    Tile::Sand => 'o',
+                });
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+impl FromStr for Cave {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let paths: Vec<Vec<Pos>> = s
+            .lines()
+            .map(|line| {
+                line.split(" -> ")
+                    .map(|point| {
+                        let (x, y) = point
+                            .split_once(',')
+                            .ok_or_else(|| anyhow!("Invalid point: {point}"))?;
+                        Ok((x.parse()?, y.parse()?))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_>>()?;
+
+        let points = paths.iter().flatten();
+        let max_rock_y = points
+            .clone()
+            .map(|&(_, y)| y)
+            .max()
+            .ok_or_else(|| anyhow!("The scan contains no rock"))?;
+        let floor_y = max_rock_y + 2;
+        // Sand on the floor forms a triangle below the source, which is never wider than this.
+        // For a deep cave it reaches left of x = 0, so the bounds are signed.
+        let (source_x, half_width) = (SAND_SOURCE.0 as i64, floor_y as i64);
+        let (min_x, max_x) = points
+            .map(|&(x, _)| x as i64)
+            .chain([source_x - half_width, source_x + half_width])
+            .minmax()
+            .into_option()
+            .unwrap();
+        // Keep an air column on each side, so that sand can always move diagonally
+        let x_offset = min_x - 1;
+        let column = |x: i64| (x - x_offset) as usize;
+
+        let mut cave = Cave {
+            tiles: Grid::new(column(max_x) + 2, floor_y + 1, Tile::Air),
+            source: (column(source_x), SAND_SOURCE.1),
+            max_rock_y,
+        };
+        for path in &paths {
+            for (&(x0, y0), &(x1, y1)) in path.iter().tuple_windows() {
+                if x0 != x1 && y0 != y1 {
+                    return Err(anyhow!("Diagonal rock line from {x0},{y0} to {x1},{y1}"));
+                }
+                for x in x0.min(x1)..=x0.max(x1) {
+                    for y in y0.min(y1)..=y0.max(y1) {
+                        cave.set_tile((column(x as i64), y), Tile::Rock);
+                    }
+                }
+            }
+        }
+        Ok(cave)
+    }
+}
+
+fn input_generator(input: &str) -> Result<Cave> {
+    input.parse()
+}
+
+fn part1(input: &Cave) -> usize {
+    let mut cave = input.clone();
+    cave.pour(false)
+}
+
+fn part2(input: &Cave) -> usize {
+    let mut cave = input.clone();
+    cave.pour(true)
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day14>();
+
+struct Day14;
+
+impl Solution for Day14 {
+    type Input = Cave;
+
+    const DAY: u32 = 14;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_display() -> Result<()> {
+    let mut cave = input_generator(&example_input(14, Part::One, 0)?)?;
+    let expected = "\
+......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+........#.
+........#.
+#########.
+";
+    assert_eq!(expected, cave.display());
+
+    cave.pour(false);
+    let expected = example_input(14, Part::One, 6)? + "\n";
+    assert_eq!(expected, cave.display());
+    Ok(())
+}
+
+#[test]
+fn test_deep_cave() -> Result<()> {
+    // The floor is more than 500 below the source, and there is rock at x = 0
+    let cave = input_generator("0,600 -> 2,600")?;
+    assert_eq!(0, part1(&cave));
+    // The whole triangle of 602 rows, without the rocks and the tile shadowed by them
+    assert_eq!(602 * 602 - 3 - 1, part2(&cave));
+    Ok(())
+}
@@ -0,0 +1,322 @@
+//! # Day 15: Beacon Exclusion Zone
+//!
+//! ## --- Part One ---
+//!
+//! You feel the ground rumble again as the distress signal leads you to a large network of subterranean tunnels.
+//! You don't have time to search them all, but you don't need to: your pack contains a set of deployable _sensors_ that you imagine were originally built to locate lost Elves.
+//!
+//! The sensors aren't very powerful, but that's okay; your handheld device indicates that you're close enough to the source of the distress signal to use them.
+//! You pull the emergency sensor system out of your pack, hit the big button on top, and the sensors zoom off down the tunnels.
+//!
+//! Once a sensor finds a spot it thinks will give it a good reading, it attaches itself to a hard surface and begins monitoring for the nearest signal source _beacon_.
+//! Sensors and beacons always exist at integer coordinates.
+//! Each sensor knows its own position and can _determine the position of a beacon precisely_; however, sensors can only lock on to the one beacon _closest to the sensor_ as measured by the [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry).
+//! (There is never a tie where two beacons are the same distance to a sensor.)
+//!
+//! It doesn't take long for the sensors to report back their positions and closest beacons (your puzzle input).
+//! For example:
+//!
+//! ```text
+//! Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+//! Sensor at x=9, y=16: closest beacon is at x=10, y=16
+//! Sensor at x=13, y=2: closest beacon is at x=15, y=3
+//! Sensor at x=12, y=14: closest beacon is at x=10, y=16
+//! Sensor at x=10, y=20: closest beacon is at x=10, y=16
+//! Sensor at x=14, y=17: closest beacon is at x=10, y=16
+//! Sensor at x=8, y=7: closest beacon is at x=2, y=10
+//! Sensor at x=2, y=0: closest beacon is at x=2, y=10
+//! Sensor at x=0, y=11: closest beacon is at x=2, y=10
+//! Sensor at x=20, y=14: closest beacon is at x=25, y=17
+//! Sensor at x=17, y=20: closest beacon is at x=21, y=22
+//! Sensor at x=16, y=7: closest beacon is at x=15, y=3
+//! Sensor at x=14, y=3: closest beacon is at x=15, y=3
+//! Sensor at x=20, y=1: closest beacon is at x=15, y=3
+//! ```
+//!
+//! So, consider the sensor at `2,18`; the closest beacon to it is at `-2,15`.
+//! For the sensor at `9,16`, the closest beacon to it is at `10,16`.
+//!
+//! Drawing sensors as `S` and beacons as `B`, the above arrangement of sensors and beacons looks like this:
+//!
+//! ```text
+//!                1    1    2    2
+//!      0    5    0    5    0    5
+//!  0 ....S.......................
+//!  1 ......................S.....
+//!  2 ...............S............
+//!  3 ................SB..........
+//!  4 ............................
+//!  5 ............................
+//!  6 ............................
+//!  7 ..........S.......S.........
+//!  8 ............................
+//!  9 ............................
+//! 10 ....B.......................
+//! 11 ..S.........................
+//! 12 ............................
+//! 13 ............................
+//! 14 ..............S.......S.....
+//! 15 B...........................
+//! 16 ...........SB...............
+//! 17 ................S..........B
+//! 18 ....S.......................
+//! 19 ............................
+//! 20 ............S......S........
+//! 21 ............................
+//! 22 .......................B....
+//! ```
+//!
+//! This isn't necessarily a comprehensive map of all beacons in the area, though.
+//! Because each sensor only identifies its closest beacon, if a sensor detects a beacon, you know there are no other beacons that close or closer to that sensor.
+//! There could still be beacons that just happen to not be the closest beacon to any sensor.
+//! Consider the sensor at `8,7`:
+//!
+//! ```text
+//!                1    1    2    2
+//!      0    5    0    5    0    5
+//! -2 ..........#.................
+//! -1 .........###................
+//!  0 ....S...#####...............
+//!  1 .......#######........S.....
+//!  2 ......#########S............
+//!  3 .....###########SB..........
+//!  4 ....#############...........
+//!  5 ...###############..........
+//!  6 ..#################.........
+//!  7 .#########S#######S#........
+//!  8 ..#################.........
+//!  9 ...###############..........
+//! 10 ....B############...........
+//! 11 ..S..###########............
+//! 12 ......#########.............
+//! 13 .......#######..............
+//! 14 ........#####.S.......S.....
+//! 15 B........###................
+//! 16 ..........#SB...............
+//! 17 ................S..........B
+//! 18 ....S.......................
+//! 19 ............................
+//! 20 ............S......S........
+//! 21 ............................
+//! 22 .......................B....
+//! ```
+//!
+//! This sensor's closest beacon is at `2,10`, and so you know there are no beacons that close or closer (in any positions marked `#`).
+//!
+//! None of the detected beacons seem to be producing the distress signal, so you'll need to work out where the distress beacon is by working out where it _isn't_.
+//! For now, keep things simple by counting the positions where a beacon cannot possibly be along just a single row.
+//!
+//! So, suppose you have an arrangement of beacons and sensors like in the example above and, just in the row where `y=10`, you'd like to count the number of positions a beacon cannot possibly exist.
+//! The coverage from all sensors near that row looks like this:
+//!
+//! ```text
+//!                  1    1    2    2
+//!        0    5    0    5    0    5
+//!  9 ...#########################...
+//! 10 ..####B######################..
+//! 11 .###S#############.###########.
+//! ```
+//!
+//! In this example, in the row where `y=10`, there are _`26`_ positions where a beacon cannot be present.
+//!
+//! Consult the report from the sensors you just deployed.
+//! _In the row where `y=2000000`, how many positions cannot contain a beacon?_
+//!
+//! ## --- Part Two ---
+//!
+//! Your handheld device indicates that the distress signal is coming from a beacon nearby.
+//! The distress beacon is not detected by any sensor, but the distress beacon must have `x` and `y` coordinates each no lower than `0` and no larger than `4000000`.
+//!
+//! To isolate the distress beacon's signal, you need to determine its _tuning frequency_, which can be found by multiplying its `x` coordinate by `4000000` and then adding its `y` coordinate.
+//!
+//! In the example above, the search space is smaller: instead, the `x` and `y` coordinates can each be at most `20`.
+//! With this reduced search area, there is only a single position that could have a beacon: `x=14, y=11`.
+//! The tuning frequency for this distress beacon is _`56000011`_.
+//!
+//! Find the only possible position for the distress beacon.
+//! _What is its tuning frequency?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+type Pos = (i64, i64);
+
+#[derive(Inpt, Debug)]
+#[inpt(regex = r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)")]
+struct Sensor {
+    x: i64,
+    y: i64,
+    beacon_x: i64,
+    beacon_y: i64,
+}
+
+impl Sensor {
+    /// Distance to the closest beacon, no other beacon can be this close
+    fn radius(&self) -> i64 {
+        (self.x - self.beacon_x).abs() + (self.y - self.beacon_y).abs()
+    }
+
+    fn covers(&self, (x, y): Pos) -> bool {
+        (self.x - x).abs() + (self.y - y).abs() <= self.radius()
+    }
+
+    /// Inclusive range of `x` coordinates the sensor covers in row `y`
+    fn row_interval(&self, y: i64) -> Option<(i64, i64)> {
+        let half_width = self.radius() - (self.y - y).abs();
+        (half_width >= 0).then(|| (self.x - half_width, self.x + half_width))
+    }
+}
+
+/// Merge inclusive intervals into a sorted list of disjoint intervals.
+fn merge_intervals(mut intervals: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    intervals.sort_unstable();
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Number of positions in row `y` which cannot contain a beacon.
+fn excluded_in_row(sensors: &[Sensor], y: i64) -> usize {
+    let intervals = merge_intervals(sensors.iter().filter_map(|s| s.row_interval(y)).collect());
+    let covered: i64 = intervals.iter().map(|(start, end)| end - start + 1).sum();
+    // Known beacons in the row are covered, but obviously can contain a beacon
+    let beacons = sensors
+        .iter()
+        .filter(|s| s.beacon_y == y)
+        .map(|s| s.beacon_x)
+        .unique()
+        .filter(|&x| intervals.iter().any(|&(start, end)| (start..=end).contains(&x)))
+        .count();
+    covered as usize - beacons
+}
+
+/// Find the single position in `0..=bound` for both coordinates which no sensor covers.
+///
+/// Such a position has to lie directly outside the diamond covered by some sensors.
+/// Each diamond boundary is formed by lines `x + y = a` and `x - y = b`.
+/// The position is either at an intersection of two such lines or where a line meets the edge of the search area.
+fn find_distress_beacon(sensors: &[Sensor], bound: i64) -> Option<Pos> {
+    let mut sum_lines = Vec::new();
+    let mut diff_lines = Vec::new();
+    for s in sensors {
+        let r = s.radius() + 1;
+        sum_lines.extend([s.x + s.y - r, s.x + s.y + r]);
+        diff_lines.extend([s.x - s.y - r, s.x - s.y + r]);
+    }
+    sum_lines.sort_unstable();
+    sum_lines.dedup();
+    diff_lines.sort_unstable();
+    diff_lines.dedup();
+
+    let intersections = sum_lines
+        .iter()
+        .cartesian_product(&diff_lines)
+        .filter(|&(a, b)| (a + b) % 2 == 0)
+        .map(|(a, b)| ((a + b) / 2, (a - b) / 2));
+    let on_edges = sum_lines
+        .iter()
+        .flat_map(|&a| [(0, a), (a, 0), (bound, a - bound), (a - bound, bound)])
+        .chain(
+            diff_lines
+                .iter()
+                .flat_map(|&b| [(0, -b), (b, 0), (bound, bound - b), (b + bound, bound)]),
+        );
+    let corners = [(0, 0), (0, bound), (bound, 0), (bound, bound)];
+
+    intersections
+        .chain(on_edges)
+        .chain(corners)
+        .filter(|&(x, y)| (0..=bound).contains(&x) && (0..=bound).contains(&y))
+        .find(|&pos| !sensors.iter().any(|s| s.covers(pos)))
+}
+
+fn tuning_frequency((x, y): Pos) -> i64 {
+    x * 4_000_000 + y
+}
+
+/// The sensors, with the row and search area asked about, which are smaller in the example
+struct Scan {
+    sensors: Vec<Sensor>,
+    /// Row to count the excluded positions in for part 1
+    row: i64,
+    /// Largest coordinate of the distress beacon for part 2
+    bound: i64,
+}
+
+fn input_generator(input: &str) -> Result<Vec<Sensor>> {
+    input
+        .lines()
+        .map(|line| inpt(line).map_err(|err| anyhow!("Invalid sensor {line:?}: {err}")))
+        .collect()
+}
+
+fn part1(input: &Scan) -> usize {
+    excluded_in_row(&input.sensors, input.row)
+}
+
+fn part2(input: &Scan) -> Result<i64> {
+    find_distress_beacon(&input.sensors, input.bound)
+        .map(tuning_frequency)
+        .ok_or_else(|| anyhow!("No position up to {} is uncovered", input.bound))
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day15>();
+
+struct Day15;
+
+impl Solution for Day15 {
+    type Input = Scan;
+
+    const DAY: u32 = 15;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Scan {
+            sensors: input_generator(input)?,
+            row: 2_000_000,
+            bound: 4_000_000,
+        })
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input> {
+        Ok(Scan {
+            sensors: input_generator(input)?,
+            row: 10,
+            bound: 20,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_example_parameters() -> Result<()> {
+    let example = example_input(15, Part::One, 0)?;
+    let scan = Day15::parse_example(&example)?;
+    assert_eq!((10, 20), (scan.row, scan.bound));
+    let scan = Day15::parse(&example)?;
+    assert_eq!((2_000_000, 4_000_000), (scan.row, scan.bound));
+    assert!(input_generator("Sensor at x=2, y=18").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_find_distress_beacon_in_corner() -> Result<()> {
+    // A single sensor covering everything but the bottom right corner
+    let values = input_generator("Sensor at x=0, y=0: closest beacon is at x=0, y=39")?;
+    assert_eq!(Some((20, 20)), find_distress_beacon(&values, 20));
+    Ok(())
+}
@@ -0,0 +1,434 @@
+//! # Day 16: Proboscidea Volcanium
+//!
+//! ## --- Part One ---
+//!
+//! The sensors have led you to the origin of the distress signal: yet another handheld device, just like the one the Elves gave you.
+//! However, you don't see any Elves around; instead, the device is surrounded by elephants!
+//! They must have gotten lost in these tunnels, and one of the elephants apparently figured out how to turn on the distress signal.
+//!
+//! The ground rumbles again, much stronger this time.
+//! What kind of cave is this, exactly?
+//! You scan the cave with your handheld device; it reports mostly igneous rock, some ash, pockets of pressurized gas, magma... this isn't just a cave, it's a volcano!
+//!
+//! You need to get the elephants out of here, quickly.
+//! Your device estimates that you have _30 minutes_ before the volcano erupts, so you don't have time to go back out the way you came in.
+//!
+//! You scan the cave for other options and discover a network of pipes and pressure-release _valves_.
+//! You aren't sure how such a system got into a volcano, but you don't have time to complain; your device produces a report (your puzzle input) of each valve's _flow rate_ if it were opened (in pressure per minute) and the tunnels you could use to move between the valves.
+//!
+//! There's even a valve in the room you and the elephants are currently standing in labeled `AA`.
+//! You estimate it will take you one minute to open a single valve and one minute to follow any tunnel from one valve to another.
+//! What is the most pressure you could release?
+//!
+//! For example, suppose you had the following scan output:
+//!
+//! ```text
+//! Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+//! Valve BB has flow rate=13; tunnels lead to valves CC, AA
+//! Valve CC has flow rate=2; tunnels lead to valves DD, BB
+//! Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+//! Valve EE has flow rate=3; tunnels lead to valves FF, DD
+//! Valve FF has flow rate=0; tunnels lead to valves EE, GG
+//! Valve GG has flow rate=0; tunnels lead to valves FF, HH
+//! Valve HH has flow rate=22; tunnel leads to valve GG
+//! Valve II has flow rate=0; tunnels lead to valves AA, JJ
+//! Valve JJ has flow rate=21; tunnel leads to valve II
+//! ```
+//!
+//! All of the valves begin _closed_.
+//! You start at valve `AA`, but it must be damaged or jammed or something: its flow rate is `0`, so there's no point in opening it.
+//! However, you could spend one minute moving to valve `BB` and another minute opening it; doing so would release pressure during the remaining _28 minutes_ at a flow rate of `13`, a total eventual pressure release of `28 * 13 = 364`.
+//! Then, you could spend your third minute moving to valve `CC` and your fourth minute opening it, providing an additional _26 minutes_ of eventual pressure release at a flow rate of `2`, or _`52`_ total pressure released by valve `CC`.
+//!
+//! Making your way through the tunnels like this, you could probably open many or all of the valves by the time 30 minutes have elapsed.
+//! However, you need to release as much pressure as possible, so you'll need to be methodical.
+//! Instead, consider this approach:
+//!
+//! ```text
+//! == Minute 1 ==
+//! No valves are open.
+//! You move to valve DD.
+//!
+//! == Minute 2 ==
+//! No valves are open.
+//! You open valve DD.
+//!
+//! == Minute 3 ==
+//! Valve DD is open, releasing 20 pressure.
+//! You move to valve CC.
+//!
+//! == Minute 4 ==
+//! Valve DD is open, releasing 20 pressure.
+//! You move to valve BB.
+//!
+//! == Minute 5 ==
+//! Valve DD is open, releasing 20 pressure.
+//! You open valve BB.
+//!
+//! == Minute 6 ==
+//! Valves BB and DD are open, releasing 33 pressure.
+//! You move to valve AA.
+//!
+//! == Minute 7 ==
+//! Valves BB and DD are open, releasing 33 pressure.
+//! You move to valve II.
+//!
+//! == Minute 8 ==
+//! Valves BB and DD are open, releasing 33 pressure.
+//! You move to valve JJ.
+//!
+//! == Minute 9 ==
+//! Valves BB and DD are open, releasing 33 pressure.
+//! You open valve JJ.
+//!
+//! == Minute 10 ==
+//! Valves BB, DD, and JJ are open, releasing 54 pressure.
+//! You move to valve II.
+//!
+//! == Minute 11 ==
+//! Valves BB, DD, and JJ are open, releasing 54 pressure.
+//! You move to valve AA.
+//!
+//! == Minute 12 ==
+//! Valves BB, DD, and JJ are open, releasing 54 pressure.
+//! You move to valve DD.
+//!
+//! == Minute 13 ==
+//! Valves BB, DD, and JJ are open, releasing 54 pressure.
+//! You move to valve EE.
+//!
+//! == Minute 14 ==
+//! Valves BB, DD, and JJ are open, releasing 54 pressure.
+//! You move to valve FF.
+//!
+//! == Minute 15 ==
+//! Valves BB, DD, and JJ are open, releasing 54 pressure.
+//! You move to valve GG.
+//!
+//! == Minute 16 ==
+//! Valves BB, DD, and JJ are open, releasing 54 pressure.
+//! You move to valve HH.
+//!
+//! == Minute 17 ==
+//! Valves BB, DD, and JJ are open, releasing 54 pressure.
+//! You open valve HH.
+//!
+//! == Minute 18 ==
+//! Valves BB, DD, HH, and JJ are open, releasing 76 pressure.
+//! You move to valve GG.
+//!
+//! == Minute 19 ==
+//! Valves BB, DD, HH, and JJ are open, releasing 76 pressure.
+//! You move to valve FF.
+//!
+//! == Minute 20 ==
+//! Valves BB, DD, HH, and JJ are open, releasing 76 pressure.
+//! You move to valve EE.
+//!
+//! == Minute 21 ==
+//! Valves BB, DD, HH, and JJ are open, releasing 76 pressure.
+//! You open valve EE.
+//!
+//! == Minute 22 ==
+//! Valves BB, DD, EE, HH, and JJ are open, releasing 79 pressure.
+//! You move to valve DD.
+//!
+//! == Minute 23 ==
+//! Valves BB, DD, EE, HH, and JJ are open, releasing 79 pressure.
+//! You move to valve CC.
+//!
+//! == Minute 24 ==
+//! Valves BB, DD, EE, HH, and JJ are open, releasing 79 pressure.
+//! You open valve CC.
+//!
+//! == Minute 25 ==
+//! Valves BB, CC, DD, EE, HH, and JJ are open, releasing 81 pressure.
+//!
+//! == Minute 26 ==
+//! Valves BB, CC, DD, EE, HH, and JJ are open, releasing 81 pressure.
+//!
+//! == Minute 27 ==
+//! Valves BB, CC, DD, EE, HH, and JJ are open, releasing 81 pressure.
+//!
+//! == Minute 28 ==
+//! Valves BB, CC, DD, EE, HH, and JJ are open, releasing 81 pressure.
+//!
+//! == Minute 29 ==
+//! Valves BB, CC, DD, EE, HH, and JJ are open, releasing 81 pressure.
+//!
+//! == Minute 30 ==
+//! Valves BB, CC, DD, EE, HH, and JJ are open, releasing 81 pressure.
+//! ```
+//!
+//! This approach lets you release the most pressure possible in 30 minutes with this valve layout, _`1651`_.
+//!
+//! Work out the steps to release the most pressure in 30 minutes.
+//! _What is the most pressure you can release?_
+//!
+//! ## --- Part Two ---
+//!
+//! You're worried that even with an optimal approach, the pressure released won't be enough.
+//! What if you got one of the elephants to help you?
+//!
+//! It would take you 4 minutes to teach an elephant how to open the right valves in the right order, leaving you with only _26 minutes_ to actually execute your plan.
+//! Would having two of you working together be better, even if it means having less time?
+//! (Assume that you teach the elephant before opening any valves yourself, giving you both the same full 26 minutes.)
+//!
+//! In the example above, you could teach the elephant to help you as follows:
+//!
+//! ```text
+//! == Minute 1 ==
+//! No valves are open.
+//! You move to valve II.
+//! The elephant moves to valve DD.
+//!
+//! == Minute 2 ==
+//! No valves are open.
+//! You move to valve JJ.
+//! The elephant opens valve DD.
+//!
+//! == Minute 3 ==
+//! Valve DD is open, releasing 20 pressure.
+//! You open valve JJ.
+//! The elephant moves to valve EE.
+//!
+//! == Minute 4 ==
+//! Valves DD and JJ are open, releasing 41 pressure.
+//! You move to valve II.
+//! The elephant moves to valve FF.
+//!
+//! == Minute 5 ==
+//! Valves DD and JJ are open, releasing 41 pressure.
+//! You move to valve AA.
+//! The elephant moves to valve GG.
+//!
+//! == Minute 6 ==
+//! Valves DD and JJ are open, releasing 41 pressure.
+//! You move to valve BB.
+//! The elephant moves to valve HH.
+//!
+//! == Minute 7 ==
+//! Valves DD and JJ are open, releasing 41 pressure.
+//! You open valve BB.
+//! The elephant opens valve HH.
+//!
+//! == Minute 8 ==
+//! Valves BB, DD, HH, and JJ are open, releasing 76 pressure.
+//! You move to valve CC.
+//! The elephant moves to valve GG.
+//!
+//! == Minute 9 ==
+//! Valves BB, DD, HH, and JJ are open, releasing 76 pressure.
+//! You open valve CC.
+//! The elephant moves to valve FF.
+//!
+//! == Minute 10 ==
+//! Valves BB, CC, DD, HH, and JJ are open, releasing 78 pressure.
+//! The elephant moves to valve EE.
+//!
+//! == Minute 11 ==
+//! Valves BB, CC, DD, HH, and JJ are open, releasing 78 pressure.
+//! The elephant opens valve EE.
+//!
+//! (At this point, all valves are open.)
+//!
+//! == Minute 12 ==
+//! Valves BB, CC, DD, EE, HH, and JJ are open, releasing 81 pressure.
+//!
+//! ...
+//!
+//! == Minute 20 ==
+//! Valves BB, CC, DD, EE, HH, and JJ are open, releasing 81 pressure.
+//!
+//! ...
+//!
+//! == Minute 26 ==
+//! Valves BB, CC, DD, EE, HH, and JJ are open, releasing 81 pressure.
+//! ```
+//!
+//! With the elephant helping, after 26 minutes, the best you could do would release a total of _`1707`_ pressure.
+//!
+//! _With you and an elephant working together for 26 minutes, what is the most pressure you could release?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+const START_VALVE: &str = "AA";
+
+#[derive(Inpt, Debug)]
+#[inpt(regex = r"Valve (\w+) has flow rate=(\d+); tunnels? leads? to valves? (.+)")]
+struct ValveScan<'a> {
+    name: &'a str,
+    flow_rate: u32,
+    tunnels: &'a str,
+}
+
+/// The valve network reduced to the valves worth opening.
+#[derive(Debug, Clone)]
+struct Network {
+    /// Flow rate of each valve with a non-zero flow rate
+    flow_rates: Vec<u32>,
+    /// Minutes to walk between any two of the non-zero valves, indexed like `flow_rates`
+    distances: Vec<Vec<u32>>,
+    /// Minutes to walk from the start valve to each of the non-zero valves
+    start_distances: Vec<u32>,
+}
+
+impl FromStr for Network {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let scans: Vec<ValveScan<'_>> = s
+            .lines()
+            .map(|line| inpt(line).map_err(|err| anyhow!("Invalid valve {line:?}: {err}")))
+            .collect::<Result<_>>()?;
+
+        // Intern the two-letter names into indices
+        let index: HashMap<&str, usize> = scans
+            .iter()
+            .enumerate()
+            .map(|(idx, scan)| (scan.name, idx))
+            .collect();
+        let lookup = |name: &str| {
+            index
+                .get(name)
+                .copied()
+                .ok_or_else(|| anyhow!("Unknown valve {name}"))
+        };
+
+        // All-pairs shortest paths with Floyd-Warshall
+        let n = scans.len();
+        let mut dist = vec![vec![u32::MAX / 2; n]; n];
+        for (idx, scan) in scans.iter().enumerate() {
+            dist[idx][idx] = 0;
+            for tunnel in scan.tunnels.split(", ") {
+                dist[idx][lookup(tunnel)?] = 1;
+            }
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    dist[i][j] = dist[i][j].min(dist[i][k] + dist[k][j]);
+                }
+            }
+        }
+
+        let useful = (0..n).filter(|&idx| scans[idx].flow_rate > 0).collect_vec();
+        // The DP table has one entry per subset of these valves
+        if useful.len() > 20 {
+            return Err(anyhow!("Too many valves with a non-zero flow rate: {}", useful.len()));
+        }
+        let start = lookup(START_VALVE)?;
+        Ok(Self {
+            flow_rates: useful.iter().map(|&idx| scans[idx].flow_rate).collect(),
+            distances: useful
+                .iter()
+                .map(|&from| useful.iter().map(|&to| dist[from][to]).collect())
+                .collect(),
+            start_distances: useful.iter().map(|&to| dist[start][to]).collect(),
+        })
+    }
+}
+
+impl Network {
+    /// Most pressure which can be released in `minutes` for each set of opened valves.
+    ///
+    /// The result is indexed by a bitmask of the opened valves.
+    /// Only the valves in the bitmask may be opened, but not all of them need to be.
+    fn best_per_mask(&self, minutes: u32) -> Vec<u32> {
+        let mut best = vec![0; 1 << self.flow_rates.len()];
+        for (valve, &dist) in self.start_distances.iter().enumerate() {
+            self.visit(valve, dist, minutes, 0, 0, &mut best);
+        }
+
+        // Propagate to supersets, so that each entry holds the best over all of its subsets
+        for bit in 0..self.flow_rates.len() {
+            for mask in 0..best.len() {
+                if mask & (1 << bit) != 0 {
+                    best[mask] = best[mask].max(best[mask ^ (1 << bit)]);
+                }
+            }
+        }
+        best
+    }
+
+    /// Walk to `valve` and open it, then try every valve which is still closed next.
+    fn visit(
+        &self,
+        valve: usize,
+        walk: u32,
+        minutes_left: u32,
+        opened: usize,
+        released: u32,
+        best: &mut [u32],
+    ) {
+        // Walking there and opening the valve needs to leave at least one minute for it to matter
+        let Some(minutes_left) = minutes_left.checked_sub(walk + 1).filter(|&m| m > 0) else {
+            return;
+        };
+        let opened = opened | (1 << valve);
+        let released = released + minutes_left * self.flow_rates[valve];
+        best[opened] = best[opened].max(released);
+
+        for (next, &dist) in self.distances[valve].iter().enumerate() {
+            if opened & (1 << next) == 0 {
+                self.visit(next, dist, minutes_left, opened, released, best);
+            }
+        }
+    }
+}
+
+fn input_generator(input: &str) -> Result<Network> {
+    input.parse()
+}
+
+fn part1(input: &Network) -> u32 {
+    let best = input.best_per_mask(30);
+    best[best.len() - 1]
+}
+
+fn part2(input: &Network) -> u32 {
+    let best = input.best_per_mask(26);
+    let all = best.len() - 1;
+    // You and the elephant open disjoint sets of valves
+    (0..=all)
+        .map(|mine| best[mine] + best[all ^ mine])
+        .max()
+        .unwrap_or(0)
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day16>();
+
+struct Day16;
+
+impl Solution for Day16 {
+    type Input = Network;
+
+    const DAY: u32 = 16;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_parse() -> Result<()> {
+    let values = input_generator(&example_input(16, Part::One, 0)?)?;
+    assert_eq!(vec![13, 2, 20, 3, 22, 21], values.flow_rates);
+    // AA -> II -> JJ
+    assert_eq!(2, values.start_distances[5]);
+    // HH -> GG -> FF -> EE -> DD -> AA -> II -> JJ
+    assert_eq!(7, values.distances[4][5]);
+    Ok(())
+}
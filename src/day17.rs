@@ -0,0 +1,598 @@
+//! # Day 17: Pyroclastic Flow
+//!
+//! ## --- Part One ---
+//!
+//! Your handheld device has located an alternative exit from the cave for you and the elephants.
+//! The ground is rumbling almost continuously now, but the strange valves bought you some time.
+//! It's definitely getting warmer in here, though.
+//!
+//! The tunnels eventually open into a very tall, narrow chamber.
+//! Large, oddly-shaped rocks are falling into the chamber from above, presumably due to all the rumbling.
+//! If you can't work out where the rocks will fall next, you might be crushed!
+//!
+//! The five types of rocks have the following peculiar shapes, where `#` is rock and `.` is empty space:
+//!
+//! ```text
+//! ####
+//!
+//! .#.
+//! ###
+//! .#.
+//!
+//! ..#
+//! ..#
+//! ###
+//!
+//! #
+//! #
+//! #
+//! #
+//!
+//! ##
+//! ##
+//! ```
+//!
+//! The rocks fall in the order shown above: first the `-` shape, then the `+` shape, and so on.
+//! Once the end of the list is reached, the same order repeats: the `-` shape falls first, sixth, 11th, 16th, etc.
+//!
+//! The rocks don't spin, but they do get pushed around by jets of hot gas coming out of the walls themselves.
+//! A quick scan reveals the effect the jets of hot gas will have on the rocks as they fall (your puzzle input).
+//!
+//! For example, suppose this was the jet pattern in your cave:
+//!
+//! ```text
+//! >>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>
+//! ```
+//!
+//! In jet patterns, `<` means a push to the left, while `>` means a push to the right.
+//! The pattern above means that the jets will push a falling rock right, then right, then right, then left, then left, then right, and so on.
+//! If the end of the list is reached, it repeats.
+//!
+//! The tall, vertical chamber is exactly _seven units wide_.
+//! Each rock appears so that its left edge is two units away from the left wall and its bottom edge is three units above the highest rock in the room (or the floor, if there isn't one).
+//!
+//! After a rock appears, it alternates between _being pushed by a jet of hot gas_ one unit (in the direction indicated by the next symbol in the jet pattern) and then _falling one unit down_.
+//! If any movement would cause any part of the rock to move into the walls, floor, or a stopped rock, the movement instead does not occur.
+//! If a _downward_ movement would have caused a falling rock to move into the floor or an already-fallen rock, the falling rock stops where it is (having landed on something) and a new rock immediately begins falling.
+//!
+//! Drawing falling rocks with `@` and stopped rocks with `#`, the jet pattern in the example above manifests as follows:
+//!
+//! ```text
+//! The first rock begins falling:
+//! |..@@@@.|
+//! |.......|
+//! |.......|
+//! |.......|
+//! +-------+
+//!
+//! Jet of gas pushes rock right:
+//! |...@@@@|
+//! |.......|
+//! |.......|
+//! |.......|
+//! +-------+
+//!
+//! Rock falls 1 unit:
+//! |...@@@@|
+//! |.......|
+//! |.......|
+//! +-------+
+//!
+//! Jet of gas pushes rock right, but nothing happens:
+//! |...@@@@|
+//! |.......|
+//! |.......|
+//! +-------+
+//!
+//! Rock falls 1 unit:
+//! |...@@@@|
+//! |.......|
+//! +-------+
+//!
+//! Jet of gas pushes rock right, but nothing happens:
+//! |...@@@@|
+//! |.......|
+//! +-------+
+//!
+//! Rock falls 1 unit:
+//! |...@@@@|
+//! +-------+
+//!
+//! Jet of gas pushes rock left:
+//! |..@@@@.|
+//! +-------+
+//!
+//! Rock falls 1 unit, causing it to come to rest:
+//! |..####.|
+//! +-------+
+//!
+//! A new rock begins falling:
+//! |...@...|
+//! |..@@@..|
+//! |...@...|
+//! |.......|
+//! |.......|
+//! |.......|
+//! |..####.|
+//! +-------+
+//!
+//! Jet of gas pushes rock left:
+//! |..@....|
+//! |.@@@...|
+//! |..@....|
+//! |.......|
+//! |.......|
+//! |.......|
+//! |..####.|
+//! +-------+
+//!
+//! Rock falls 1 unit:
+//! |..@....|
+//! |.@@@...|
+//! |..@....|
+//! |.......|
+//! |.......|
+//! |..####.|
+//! +-------+
+//!
+//! Jet of gas pushes rock right:
+//! |...@...|
+//! |..@@@..|
+//! |...@...|
+//! |.......|
+//! |.......|
+//! |..####.|
+//! +-------+
+//!
+//! Rock falls 1 unit:
+//! |...@...|
+//! |..@@@..|
+//! |...@...|
+//! |.......|
+//! |..####.|
+//! +-------+
+//!
+//! Jet of gas pushes rock left:
+//! |..@....|
+//! |.@@@...|
+//! |..@....|
+//! |.......|
+//! |..####.|
+//! +-------+
+//!
+//! Rock falls 1 unit:
+//! |..@....|
+//! |.@@@...|
+//! |..@....|
+//! |..####.|
+//! +-------+
+//!
+//! Jet of gas pushes rock right:
+//! |...@...|
+//! |..@@@..|
+//! |...@...|
+//! |..####.|
+//! +-------+
+//!
+//! Rock falls 1 unit, causing it to come to rest:
+//! |...#...|
+//! |..###..|
+//! |...#...|
+//! |..####.|
+//! +-------+
+//!
+//! A new rock begins falling:
+//! |....@..|
+//! |....@..|
+//! |..@@@..|
+//! |.......|
+//! |.......|
+//! |.......|
+//! |...#...|
+//! |..###..|
+//! |...#...|
+//! |..####.|
+//! +-------+
+//! ```
+//!
+//! The moment each of the next few rocks begins falling, you would see this:
+//!
+//! ```text
+//! |..@....|
+//! |..@....|
+//! |..@....|
+//! |..@....|
+//! |.......|
+//! |.......|
+//! |.......|
+//! |..#....|
+//! |..#....|
+//! |####...|
+//! |..###..|
+//! |...#...|
+//! |..####.|
+//! +-------+
+//!
+//! |..@@...|
+//! |..@@...|
+//! |.......|
+//! |.......|
+//! |.......|
+//! |....#..|
+//! |..#.#..|
+//! |..#.#..|
+//! |#####..|
+//! |..###..|
+//! |...#...|
+//! |..####.|
+//! +-------+
+//!
+//! |..@@@@.|
+//! |.......|
+//! |.......|
+//! |.......|
+//! |....##.|
+//! |....##.|
+//! |....#..|
+//! |..#.#..|
+//! |..#.#..|
+//! |#####..|
+//! |..###..|
+//! |...#...|
+//! |..####.|
+//! +-------+
+//!
+//! |...@...|
+//! |..@@@..|
+//! |...@...|
+//! |.......|
+//! |.......|
+//! |.......|
+//! |.####..|
+//! |....##.|
+//! |....##.|
+//! |....#..|
+//! |..#.#..|
+//! |..#.#..|
+//! |#####..|
+//! |..###..|
+//! |...#...|
+//! |..####.|
+//! +-------+
+//!
+//! |....@..|
+//! |....@..|
+//! |..@@@..|
+//! |.......|
+//! |.......|
+//! |.......|
+//! |..#....|
+//! |.###...|
+//! |..#....|
+//! |.####..|
+//! |....##.|
+//! |....##.|
+//! |....#..|
+//! |..#.#..|
+//! |..#.#..|
+//! |#####..|
+//! |..###..|
+//! |...#...|
+//! |..####.|
+//! +-------+
+//!
+//! |..@....|
+//! |..@....|
+//! |..@....|
+//! |..@....|
+//! |.......|
+//! |.......|
+//! |.......|
+//! |.....#.|
+//! |.....#.|
+//! |..####.|
+//! |.###...|
+//! |..#....|
+//! |.####..|
+//! |....##.|
+//! |....##.|
+//! |....#..|
+//! |..#.#..|
+//! |..#.#..|
+//! |#####..|
+//! |..###..|
+//! |...#...|
+//! |..####.|
+//! +-------+
+//!
+//! |..@@...|
+//! |..@@...|
+//! |.......|
+//! |.......|
+//! |.......|
+//! |....#..|
+//! |....#..|
+//! |....##.|
+//! |....##.|
+//! |..####.|
+//! |.###...|
+//! |..#....|
+//! |.####..|
+//! |....##.|
+//! |....##.|
+//! |....#..|
+//! |..#.#..|
+//! |..#.#..|
+//! |#####..|
+//! |..###..|
+//! |...#...|
+//! |..####.|
+//! +-------+
+//!
+//! |..@@@@.|
+//! |.......|
+//! |.......|
+//! |.......|
+//! |....#..|
+//! |....#..|
+//! |....##.|
+//! |##..##.|
+//! |######.|
+//! |.###...|
+//! |..#....|
+//! |.####..|
+//! |....##.|
+//! |....##.|
+//! |....#..|
+//! |..#.#..|
+//! |..#.#..|
+//! |#####..|
+//! |..###..|
+//! |...#...|
+//! |..####.|
+//! +-------+
+//! ```
+//!
+//! To prove to the elephants your simulation is accurate, they want to know how tall the tower will get after 2022 rocks have stopped (but before the 2023rd rock begins falling).
+//! In this example, the tower of rocks will be _`3068`_ units tall after 2022 rocks have stopped falling.
+//!
+//! _How many units tall will the tower of rocks be after 2022 rocks have stopped falling?_
+//!
+//! ## --- Part Two ---
+//!
+//! The elephants are not impressed by your simulation.
+//! They demand to know how tall the tower will be after _`1000000000000`_ rocks have stopped!
+//! Only then will they feel confident enough to proceed through the cave.
+//!
+//! In the example above, the tower would be _`1514285714288`_ units tall!
+//!
+//! _How tall will the tower be after 1000000000000 rocks have stopped?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+const CHAMBER_WIDTH: usize = 7;
+/// Number of rows from the top which make up the skyline used to detect a repeating state
+const SKYLINE_ROWS: usize = 32;
+
+/// Rock shapes as rows from the bottom up.
+///
+/// Each row is a bitmask with bit 6 being the left wall and bit 0 the right wall.
+/// The shapes are placed two units from the left wall.
+static ROCKS: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+
+#[derive(Debug, Copy, Clone)]
+enum Jet {
+    Left,
+    Right,
+}
+
+/// The chamber with all stopped rocks, one bitmask per row from the floor up.
+#[derive(Debug, Clone)]
+struct Chamber<'a> {
+    jets: &'a [Jet],
+    rows: Vec<u8>,
+    next_jet: usize,
+    next_rock: usize,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [Jet]) -> Self {
+        Self {
+            jets,
+            rows: Vec::new(),
+            next_jet: 0,
+            next_rock: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn collides(&self, rock: &[u8], bottom: usize) -> bool {
+        rock.iter()
+            .enumerate()
+            .any(|(dy, &row)| self.rows.get(bottom + dy).is_some_and(|&r| r & row != 0))
+    }
+
+    /// Let the next rock fall until it stops.
+    fn drop_rock(&mut self) {
+        let mut rock = ROCKS[self.next_rock].to_vec();
+        self.next_rock = (self.next_rock + 1) % ROCKS.len();
+        let mut bottom = self.height() + 3;
+
+        loop {
+            let jet = self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+            let pushed = match jet {
+                Jet::Left if rock.iter().all(|row| row & (1 << (CHAMBER_WIDTH - 1)) == 0) => {
+                    Some(rock.iter().map(|row| row << 1).collect_vec())
+                }
+                Jet::Right if rock.iter().all(|row| row & 0b0000001 == 0) => {
+                    Some(rock.iter().map(|row| row >> 1).collect_vec())
+                }
+                // Pushed into a wall
+                _ => None,
+            };
+            if let Some(pushed) = pushed {
+                if !self.collides(&pushed, bottom) {
+                    rock = pushed;
+                }
+            }
+
+            if bottom == 0 || self.collides(&rock, bottom - 1) {
+                break;
+            }
+            bottom -= 1;
+        }
+
+        for (dy, row) in rock.into_iter().enumerate() {
+            if bottom + dy == self.rows.len() {
+                self.rows.push(0);
+            }
+            self.rows[bottom + dy] |= row;
+        }
+    }
+
+    /// Everything which determines how the following rocks fall, assuming nothing falls deeper than the skyline.
+    fn state(&self) -> (usize, usize, Vec<u8>) {
+        let skyline = self.rows.iter().rev().take(SKYLINE_ROWS).copied().collect();
+        (self.next_rock, self.next_jet, skyline)
+    }
+
+    /// Render the top `n` rows like the puzzle description, including the floor if visible.
+    #[cfg(test)]
+    fn display_top(&self, n: usize) -> String {
+        let mut s = String::new();
+        for row in self.rows.iter().rev().take(n) {
+            s.push('|');
+            for x in (0..CHAMBER_WIDTH).rev() {
+                s.push(if row & (1 << x) != 0 { '#' } else { '.' });
+            }
+            s.push_str("|\n");
+        }
+        if n >= self.rows.len() {
+            s.push_str("+-------+\n");
+        }
+        s
+    }
+}
+
+/// Height of the tower after `rocks` rocks have stopped.
+///
+/// Once the same state repeats, the tower grows by the same amount for every further cycle.
+/// The full cycles are skipped and only the remainder is simulated.
+fn tower_height(jets: &[Jet], rocks: usize) -> usize {
+    let mut chamber = Chamber::new(jets);
+    let mut seen: HashMap<(usize, usize, Vec<u8>), (usize, usize)> = HashMap::new();
+    let mut skipped_height = 0;
+    let mut dropped = 0;
+
+    while dropped < rocks {
+        chamber.drop_rock();
+        dropped += 1;
+
+        if skipped_height == 0 && chamber.height() >= SKYLINE_ROWS {
+            if let Some((prev_dropped, prev_height)) =
+                seen.insert(chamber.state(), (dropped, chamber.height()))
+            {
+                let cycle_len = dropped - prev_dropped;
+                let cycle_height = chamber.height() - prev_height;
+                let cycles = (rocks - dropped) / cycle_len;
+                dropped += cycles * cycle_len;
+                skipped_height = cycles * cycle_height;
+            }
+        }
+    }
+
+    chamber.height() + skipped_height
+}
+
+fn input_generator(input: &str) -> Result<Vec<Jet>> {
+    let jets = input
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(anyhow!("Invalid jet: {c}")),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if jets.is_empty() {
+        return Err(anyhow!("The jet pattern is empty"));
+    }
+    Ok(jets)
+}
+
+fn part1(input: &[Jet]) -> usize {
+    tower_height(input, 2022)
+}
+
+fn part2(input: &[Jet]) -> usize {
+    tower_height(input, 1_000_000_000_000)
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day17>();
+
+struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Jet>;
+
+    const DAY: u32 = 17;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_display_top() -> Result<()> {
+    let values = input_generator(&example_input(17, Part::One, 1)?)?;
+    let mut chamber = Chamber::new(&values);
+    // Whenever a new rock begins falling in the docs, the chamber below it shows the stopped rocks
+    let walkthrough = example_input(17, Part::One, 2)?;
+    let next_few = example_input(17, Part::One, 3)?;
+    let frames = walkthrough
+        .split("\n\n")
+        .filter_map(|frame| frame.strip_prefix("A new rock begins falling:\n"))
+        .chain(next_few.split("\n\n"))
+        .collect_vec();
+    assert_eq!(10, frames.len());
+    let mut expected = String::new();
+    for (rocks, frame) in (1..).zip(frames) {
+        chamber.drop_rock();
+        expected = frame
+            .lines()
+            .skip_while(|line| line.contains('@') || *line == "|.......|")
+            .map(|line| format!("{line}\n"))
+            .collect();
+        assert_eq!(expected, chamber.display_top(chamber.height()), "{rocks} rocks");
+    }
+    assert_eq!(&expected[..20], chamber.display_top(2));
+    Ok(())
+}
+
+#[test]
+fn test_empty_pattern() {
+    assert!(input_generator("").is_err());
+    assert!(input_generator("\n").is_err());
+}
@@ -0,0 +1,159 @@
+//! # Day 18: Boiling Boulders
+//!
+//! ## --- Part One ---
+//!
+//! You and the elephants finally reach fresh air.
+//! You've emerged near the base of a large volcano that seems to be actively erupting!
+//! Fortunately, the lava seems to be flowing away from you and toward the ocean.
+//!
+//! Bits of lava are still being ejected toward you, so you're sheltering in the cavern exit a little longer.
+//! Outside the cave, you can see the lava landing in a pond and hear it loudly hissing as it solidifies.
+//!
+//! Depending on the specific compounds in the lava and speed at which it cools, it might be forming [obsidian](https://en.wikipedia.org/wiki/Obsidian)!
+//! The cooling rate should be based on the surface area of the lava droplets, so you take a quick scan of a droplet as it flies past you (your puzzle input).
+//!
+//! Because of how quickly the lava is moving, the scan isn't very good; its resolution is quite low and, as a result, it approximates the shape of the lava droplet with _1x1x1 cubes on a 3D grid_, each given as its `x,y,z` position.
+//!
+//! To approximate the surface area, count the number of sides of each cube that are not immediately connected to another cube.
+//! So, if your scan were only two adjacent cubes like `1,1,1` and `2,1,1`, each cube would have a single side covered and five sides exposed, a total surface area of _`10`_ sides.
+//!
+//! Here's a larger example:
+//!
+//! ```text
+//! 2,2,2
+//! 1,2,2
+//! 3,2,2
+//! 2,1,2
+//! 2,3,2
+//! 2,2,1
+//! 2,2,3
+//! 2,2,4
+//! 2,2,6
+//! 1,2,5
+//! 3,2,5
+//! 2,1,5
+//! 2,3,5
+//! ```
+//!
+//! In the above example, after counting up all the sides that aren't connected to another cube, the total surface area is _`64`_.
+//!
+//! _What is the surface area of your scanned lava droplet?_
+//!
+//! ## --- Part Two ---
+//!
+//! Something seems off about your calculation.
+//! The cooling rate depends on exterior surface area, but your calculation also included the surface area of air pockets trapped in the lava droplet.
+//!
+//! Instead, consider only cube sides that could be reached by the water and steam as the lava droplet tumbles into the pond.
+//! The steam will expand to reach as much as possible, completely displacing any air on the outside of the lava droplet but never expanding diagonally.
+//!
+//! In the larger example above, exactly one cube of air is trapped within the lava droplet (at `2,2,5`), so the exterior surface area of the lava droplet is _`58`_.
+//!
+//! _What is the exterior surface area of your scanned lava droplet?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+type Cube = (i32, i32, i32);
+
+fn neighbors((x, y, z): Cube) -> [Cube; 6] {
+    [
+        (x - 1, y, z),
+        (x + 1, y, z),
+        (x, y - 1, z),
+        (x, y + 1, z),
+        (x, y, z - 1),
+        (x, y, z + 1),
+    ]
+}
+
+fn input_generator(input: &str) -> Result<HashSet<Cube>> {
+    input
+        .lines()
+        .map(|line| match line.split(',').collect_vec()[..] {
+            [x, y, z] => Ok((x.parse()?, y.parse()?, z.parse()?)),
+            _ => Err(anyhow!("Invalid cube: {line}")),
+        })
+        .collect()
+}
+
+fn part1(cubes: &HashSet<Cube>) -> usize {
+    cubes
+        .iter()
+        .flat_map(|&cube| neighbors(cube))
+        .filter(|n| !cubes.contains(n))
+        .count()
+}
+
+fn part2(cubes: &HashSet<Cube>) -> usize {
+    // Pad the bounding box by one in every direction.
+    // This way the steam can flow around the droplet and the fill does not depend on where the coordinates start.
+    let bounds = |axis: fn(&Cube) -> i32| {
+        let (min, max) = cubes.iter().map(axis).minmax().into_option().unwrap_or((0, 0));
+        min - 1..=max + 1
+    };
+    let (xs, ys, zs) = (bounds(|c| c.0), bounds(|c| c.1), bounds(|c| c.2));
+    let in_bounds = |&(x, y, z): &Cube| xs.contains(&x) && ys.contains(&y) && zs.contains(&z);
+
+    // Flood fill the steam from a corner, counting every time it touches a side of the droplet
+    let start = (*xs.start(), *ys.start(), *zs.start());
+    let mut steam = HashSet::from([start]);
+    let mut stack = vec![start];
+    let mut exterior_sides = 0;
+    while let Some(cube) = stack.pop() {
+        for n in neighbors(cube) {
+            if cubes.contains(&n) {
+                exterior_sides += 1;
+            } else if in_bounds(&n) && steam.insert(n) {
+                stack.push(n);
+            }
+        }
+    }
+    exterior_sides
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day18>();
+
+struct Day18;
+
+impl Solution for Day18 {
+    type Input = HashSet<Cube>;
+
+    const DAY: u32 = 18;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_two_cubes() -> Result<()> {
+    // The first example is only given inline
+    let values = input_generator("1,1,1\n2,1,1")?;
+    assert_eq!(10, part1(&values));
+    assert_eq!(10, part2(&values));
+    Ok(())
+}
+
+#[test]
+fn test_part2_non_positive_coordinates() -> Result<()> {
+    // The same droplet moved to touch and cross zero
+    let values: HashSet<Cube> = input_generator(&example_input(18, Part::One, 0)?)?
+        .into_iter()
+        .map(|(x, y, z)| (x - 2, y - 3, z - 1))
+        .collect();
+    assert_eq!(64, part1(&values));
+    assert_eq!(58, part2(&values));
+    Ok(())
+}
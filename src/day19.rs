@@ -0,0 +1,630 @@
+//! # Day 19: Not Enough Minerals
+//!
+//! ## --- Part One ---
+//!
+//! Your scans show that the lava did indeed form obsidian!
+//!
+//! The wind has changed direction enough to stop sending lava droplets toward you, so you and the elephants exit the cave.
+//! As you do, you notice a collection of [geodes](https://en.wikipedia.org/wiki/Geode) around the pond.
+//! Perhaps you could use the obsidian to create some _geode-cracking robots_ and break them open?
+//!
+//! To collect the obsidian from the bottom of the pond, you'll need waterproof _obsidian-collecting robots_.
+//! Fortunately, there is an abundant amount of clay nearby that you can use to make them waterproof.
+//!
+//! In order to harvest the clay, you'll need special-purpose _clay-collecting robots_.
+//! To make any type of robot, you'll need _ore_, which is also plentiful but in the opposite direction from the clay.
+//!
+//! Collecting ore requires _ore-collecting robots_ with big drills.
+//! Fortunately, _you have exactly one ore-collecting robot_ in your pack that you can use to kickstart the whole operation.
+//!
+//! Each robot can collect 1 of its resource type per minute.
+//! It also takes one minute for the robot factory (also conveniently from your pack) to construct any type of robot, although it consumes the necessary resources available when construction begins.
+//!
+//! The robot factory has many _blueprints_ (your puzzle input) you can choose from, but once you've configured it with a blueprint, you can't change it.
+//! You'll need to work out which blueprint is best.
+//!
+//! For example:
+//!
+//! ```text
+//! Blueprint 1:
+//!   Each ore robot costs 4 ore.
+//!   Each clay robot costs 2 ore.
+//!   Each obsidian robot costs 3 ore and 14 clay.
+//!   Each geode robot costs 2 ore and 7 obsidian.
+//!
+//! Blueprint 2:
+//!   Each ore robot costs 2 ore.
+//!   Each clay robot costs 3 ore.
+//!   Each obsidian robot costs 3 ore and 8 clay.
+//!   Each geode robot costs 3 ore and 12 obsidian.
+//! ```
+//!
+//! (Blueprints have been line-wrapped here for legibility.
+//! The robot factory's actual assortment of blueprints are provided one blueprint per line.)
+//!
+//! The elephants are starting to look hungry, so you shouldn't take too long; you need to figure out which blueprint would maximize the number of opened geodes after _24 minutes_ by figuring out which robots to build and when to build them.
+//!
+//! Using blueprint 1 in the example above, the largest number of geodes you could open in 24 minutes is _`9`_.
+//! One way to achieve that is:
+//!
+//! ```text
+//! == Minute 1 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 1 ore.
+//!
+//! == Minute 2 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 2 ore.
+//!
+//! == Minute 3 ==
+//! Spend 2 ore to start building a clay-collecting robot.
+//! 1 ore-collecting robot collects 1 ore; you now have 1 ore.
+//! The new clay-collecting robot is ready; you now have 1 of them.
+//!
+//! == Minute 4 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 2 ore.
+//! 1 clay-collecting robot collects 1 clay; you now have 1 clay.
+//!
+//! == Minute 5 ==
+//! Spend 2 ore to start building a clay-collecting robot.
+//! 1 ore-collecting robot collects 1 ore; you now have 1 ore.
+//! 1 clay-collecting robot collects 1 clay; you now have 2 clay.
+//! The new clay-collecting robot is ready; you now have 2 of them.
+//!
+//! == Minute 6 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 2 ore.
+//! 2 clay-collecting robots collect 2 clay; you now have 4 clay.
+//!
+//! == Minute 7 ==
+//! Spend 2 ore to start building a clay-collecting robot.
+//! 1 ore-collecting robot collects 1 ore; you now have 1 ore.
+//! 2 clay-collecting robots collect 2 clay; you now have 6 clay.
+//! The new clay-collecting robot is ready; you now have 3 of them.
+//!
+//! == Minute 8 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 2 ore.
+//! 3 clay-collecting robots collect 3 clay; you now have 9 clay.
+//!
+//! == Minute 9 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 3 ore.
+//! 3 clay-collecting robots collect 3 clay; you now have 12 clay.
+//!
+//! == Minute 10 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 4 ore.
+//! 3 clay-collecting robots collect 3 clay; you now have 15 clay.
+//!
+//! == Minute 11 ==
+//! Spend 3 ore and 14 clay to start building an obsidian-collecting robot.
+//! 1 ore-collecting robot collects 1 ore; you now have 2 ore.
+//! 3 clay-collecting robots collect 3 clay; you now have 4 clay.
+//! The new obsidian-collecting robot is ready; you now have 1 of them.
+//!
+//! == Minute 12 ==
+//! Spend 2 ore to start building a clay-collecting robot.
+//! 1 ore-collecting robot collects 1 ore; you now have 1 ore.
+//! 3 clay-collecting robots collect 3 clay; you now have 7 clay.
+//! 1 obsidian-collecting robot collects 1 obsidian; you now have 1 obsidian.
+//! The new clay-collecting robot is ready; you now have 4 of them.
+//!
+//! == Minute 13 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 2 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 11 clay.
+//! 1 obsidian-collecting robot collects 1 obsidian; you now have 2 obsidian.
+//!
+//! == Minute 14 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 3 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 15 clay.
+//! 1 obsidian-collecting robot collects 1 obsidian; you now have 3 obsidian.
+//!
+//! == Minute 15 ==
+//! Spend 3 ore and 14 clay to start building an obsidian-collecting robot.
+//! 1 ore-collecting robot collects 1 ore; you now have 1 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 5 clay.
+//! 1 obsidian-collecting robot collects 1 obsidian; you now have 4 obsidian.
+//! The new obsidian-collecting robot is ready; you now have 2 of them.
+//!
+//! == Minute 16 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 2 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 9 clay.
+//! 2 obsidian-collecting robots collect 2 obsidian; you now have 6 obsidian.
+//!
+//! == Minute 17 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 3 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 13 clay.
+//! 2 obsidian-collecting robots collect 2 obsidian; you now have 8 obsidian.
+//!
+//! == Minute 18 ==
+//! Spend 2 ore and 7 obsidian to start building a geode-cracking robot.
+//! 1 ore-collecting robot collects 1 ore; you now have 2 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 17 clay.
+//! 2 obsidian-collecting robots collect 2 obsidian; you now have 3 obsidian.
+//! The new geode-cracking robot is ready; you now have 1 of them.
+//!
+//! == Minute 19 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 3 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 21 clay.
+//! 2 obsidian-collecting robots collect 2 obsidian; you now have 5 obsidian.
+//! 1 geode-cracking robot cracks 1 geode; you now have 1 open geode.
+//!
+//! == Minute 20 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 4 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 25 clay.
+//! 2 obsidian-collecting robots collect 2 obsidian; you now have 7 obsidian.
+//! 1 geode-cracking robot cracks 1 geode; you now have 2 open geodes.
+//!
+//! == Minute 21 ==
+//! Spend 2 ore and 7 obsidian to start building a geode-cracking robot.
+//! 1 ore-collecting robot collects 1 ore; you now have 3 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 29 clay.
+//! 2 obsidian-collecting robots collect 2 obsidian; you now have 2 obsidian.
+//! 1 geode-cracking robot cracks 1 geode; you now have 3 open geodes.
+//! The new geode-cracking robot is ready; you now have 2 of them.
+//!
+//! == Minute 22 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 4 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 33 clay.
+//! 2 obsidian-collecting robots collect 2 obsidian; you now have 4 obsidian.
+//! 2 geode-cracking robots crack 2 geodes; you now have 5 open geodes.
+//!
+//! == Minute 23 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 5 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 37 clay.
+//! 2 obsidian-collecting robots collect 2 obsidian; you now have 6 obsidian.
+//! 2 geode-cracking robots crack 2 geodes; you now have 7 open geodes.
+//!
+//! == Minute 24 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 6 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 41 clay.
+//! 2 obsidian-collecting robots collect 2 obsidian; you now have 8 obsidian.
+//! 2 geode-cracking robots crack 2 geodes; you now have 9 open geodes.
+//! ```
+//!
+//! However, by using blueprint 2 in the example above, you could do even better: the largest number of geodes you could open in 24 minutes is _`12`_.
+//!
+//! Determine the _quality level_ of each blueprint by _multiplying that blueprint's ID number_ with the largest number of geodes that can be opened in 24 minutes using that blueprint.
+//! In this example, the first blueprint has ID 1 and can open 9 geodes, so its quality level is _`9`_.
+//! The second blueprint has ID 2 and can open 12 geodes, so its quality level is _`24`_.
+//! Finally, if you add up the quality levels of all of the blueprints in the list, you get _`33`_.
+//!
+//! Determine the quality level of each blueprint using the largest number of geodes it could produce in 24 minutes.
+//! _What do you get if you add up the quality level of all of the blueprints in your list?_
+//!
+//! ## --- Part Two ---
+//!
+//! While you were choosing the best blueprint, the elephants found some food on their own, so you're not in as much of a hurry; you figure you probably have _32 minutes_ before the wind changes direction again and you'll need to get out of range of the erupting volcano.
+//!
+//! Unfortunately, one of the elephants _ate most of your blueprint list_!
+//! Now, only the first three blueprints in your list are intact.
+//!
+//! In 32 minutes, the largest number of geodes blueprint 1 (from the example above) can open is _`56`_.
+//! One way to achieve that is:
+//!
+//! ```text
+//! == Minute 1 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 1 ore.
+//!
+//! == Minute 2 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 2 ore.
+//!
+//! == Minute 3 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 3 ore.
+//!
+//! == Minute 4 ==
+//! 1 ore-collecting robot collects 1 ore; you now have 4 ore.
+//!
+//! == Minute 5 ==
+//! Spend 4 ore to start building an ore-collecting robot.
+//! 1 ore-collecting robot collects 1 ore; you now have 1 ore.
+//! The new ore-collecting robot is ready; you now have 2 of them.
+//!
+//! == Minute 6 ==
+//! 2 ore-collecting robots collect 2 ore; you now have 3 ore.
+//!
+//! == Minute 7 ==
+//! Spend 2 ore to start building a clay-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 3 ore.
+//! The new clay-collecting robot is ready; you now have 1 of them.
+//!
+//! == Minute 8 ==
+//! Spend 2 ore to start building a clay-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 3 ore.
+//! 1 clay-collecting robot collects 1 clay; you now have 1 clay.
+//! The new clay-collecting robot is ready; you now have 2 of them.
+//!
+//! == Minute 9 ==
+//! Spend 2 ore to start building a clay-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 3 ore.
+//! 2 clay-collecting robots collect 2 clay; you now have 3 clay.
+//! The new clay-collecting robot is ready; you now have 3 of them.
+//!
+//! == Minute 10 ==
+//! Spend 2 ore to start building a clay-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 3 ore.
+//! 3 clay-collecting robots collect 3 clay; you now have 6 clay.
+//! The new clay-collecting robot is ready; you now have 4 of them.
+//!
+//! == Minute 11 ==
+//! Spend 2 ore to start building a clay-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 3 ore.
+//! 4 clay-collecting robots collect 4 clay; you now have 10 clay.
+//! The new clay-collecting robot is ready; you now have 5 of them.
+//!
+//! == Minute 12 ==
+//! Spend 2 ore to start building a clay-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 3 ore.
+//! 5 clay-collecting robots collect 5 clay; you now have 15 clay.
+//! The new clay-collecting robot is ready; you now have 6 of them.
+//!
+//! == Minute 13 ==
+//! Spend 2 ore to start building a clay-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 3 ore.
+//! 6 clay-collecting robots collect 6 clay; you now have 21 clay.
+//! The new clay-collecting robot is ready; you now have 7 of them.
+//!
+//! == Minute 14 ==
+//! Spend 3 ore and 14 clay to start building an obsidian-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 2 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 14 clay.
+//! The new obsidian-collecting robot is ready; you now have 1 of them.
+//!
+//! == Minute 15 ==
+//! 2 ore-collecting robots collect 2 ore; you now have 4 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 21 clay.
+//! 1 obsidian-collecting robot collects 1 obsidian; you now have 1 obsidian.
+//!
+//! == Minute 16 ==
+//! Spend 3 ore and 14 clay to start building an obsidian-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 3 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 14 clay.
+//! 1 obsidian-collecting robot collects 1 obsidian; you now have 2 obsidian.
+//! The new obsidian-collecting robot is ready; you now have 2 of them.
+//!
+//! == Minute 17 ==
+//! Spend 3 ore and 14 clay to start building an obsidian-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 2 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 7 clay.
+//! 2 obsidian-collecting robots collect 2 obsidian; you now have 4 obsidian.
+//! The new obsidian-collecting robot is ready; you now have 3 of them.
+//!
+//! == Minute 18 ==
+//! 2 ore-collecting robots collect 2 ore; you now have 4 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 14 clay.
+//! 3 obsidian-collecting robots collect 3 obsidian; you now have 7 obsidian.
+//!
+//! == Minute 19 ==
+//! Spend 3 ore and 14 clay to start building an obsidian-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 3 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 7 clay.
+//! 3 obsidian-collecting robots collect 3 obsidian; you now have 10 obsidian.
+//! The new obsidian-collecting robot is ready; you now have 4 of them.
+//!
+//! == Minute 20 ==
+//! Spend 2 ore and 7 obsidian to start building a geode-cracking robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 3 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 14 clay.
+//! 4 obsidian-collecting robots collect 4 obsidian; you now have 7 obsidian.
+//! The new geode-cracking robot is ready; you now have 1 of them.
+//!
+//! == Minute 21 ==
+//! Spend 3 ore and 14 clay to start building an obsidian-collecting robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 2 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 7 clay.
+//! 4 obsidian-collecting robots collect 4 obsidian; you now have 11 obsidian.
+//! 1 geode-cracking robot cracks 1 geode; you now have 1 open geode.
+//! The new obsidian-collecting robot is ready; you now have 5 of them.
+//!
+//! == Minute 22 ==
+//! Spend 2 ore and 7 obsidian to start building a geode-cracking robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 2 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 14 clay.
+//! 5 obsidian-collecting robots collect 5 obsidian; you now have 9 obsidian.
+//! 1 geode-cracking robot cracks 1 geode; you now have 2 open geodes.
+//! The new geode-cracking robot is ready; you now have 2 of them.
+//!
+//! == Minute 23 ==
+//! Spend 2 ore and 7 obsidian to start building a geode-cracking robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 2 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 21 clay.
+//! 5 obsidian-collecting robots collect 5 obsidian; you now have 7 obsidian.
+//! 2 geode-cracking robots crack 2 geodes; you now have 4 open geodes.
+//! The new geode-cracking robot is ready; you now have 3 of them.
+//!
+//! == Minute 24 ==
+//! Spend 2 ore and 7 obsidian to start building a geode-cracking robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 2 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 28 clay.
+//! 5 obsidian-collecting robots collect 5 obsidian; you now have 5 obsidian.
+//! 3 geode-cracking robots crack 3 geodes; you now have 7 open geodes.
+//! The new geode-cracking robot is ready; you now have 4 of them.
+//!
+//! == Minute 25 ==
+//! 2 ore-collecting robots collect 2 ore; you now have 4 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 35 clay.
+//! 5 obsidian-collecting robots collect 5 obsidian; you now have 10 obsidian.
+//! 4 geode-cracking robots crack 4 geodes; you now have 11 open geodes.
+//!
+//! == Minute 26 ==
+//! Spend 2 ore and 7 obsidian to start building a geode-cracking robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 4 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 42 clay.
+//! 5 obsidian-collecting robots collect 5 obsidian; you now have 8 obsidian.
+//! 4 geode-cracking robots crack 4 geodes; you now have 15 open geodes.
+//! The new geode-cracking robot is ready; you now have 5 of them.
+//!
+//! == Minute 27 ==
+//! Spend 2 ore and 7 obsidian to start building a geode-cracking robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 4 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 49 clay.
+//! 5 obsidian-collecting robots collect 5 obsidian; you now have 6 obsidian.
+//! 5 geode-cracking robots crack 5 geodes; you now have 20 open geodes.
+//! The new geode-cracking robot is ready; you now have 6 of them.
+//!
+//! == Minute 28 ==
+//! 2 ore-collecting robots collect 2 ore; you now have 6 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 56 clay.
+//! 5 obsidian-collecting robots collect 5 obsidian; you now have 11 obsidian.
+//! 6 geode-cracking robots crack 6 geodes; you now have 26 open geodes.
+//!
+//! == Minute 29 ==
+//! Spend 2 ore and 7 obsidian to start building a geode-cracking robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 6 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 63 clay.
+//! 5 obsidian-collecting robots collect 5 obsidian; you now have 9 obsidian.
+//! 6 geode-cracking robots crack 6 geodes; you now have 32 open geodes.
+//! The new geode-cracking robot is ready; you now have 7 of them.
+//!
+//! == Minute 30 ==
+//! Spend 2 ore and 7 obsidian to start building a geode-cracking robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 6 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 70 clay.
+//! 5 obsidian-collecting robots collect 5 obsidian; you now have 7 obsidian.
+//! 7 geode-cracking robots crack 7 geodes; you now have 39 open geodes.
+//! The new geode-cracking robot is ready; you now have 8 of them.
+//!
+//! == Minute 31 ==
+//! Spend 2 ore and 7 obsidian to start building a geode-cracking robot.
+//! 2 ore-collecting robots collect 2 ore; you now have 6 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 77 clay.
+//! 5 obsidian-collecting robots collect 5 obsidian; you now have 5 obsidian.
+//! 8 geode-cracking robots crack 8 geodes; you now have 47 open geodes.
+//! The new geode-cracking robot is ready; you now have 9 of them.
+//!
+//! == Minute 32 ==
+//! 2 ore-collecting robots collect 2 ore; you now have 8 ore.
+//! 7 clay-collecting robots collect 7 clay; you now have 84 clay.
+//! 5 obsidian-collecting robots collect 5 obsidian; you now have 10 obsidian.
+//! 9 geode-cracking robots crack 9 geodes; you now have 56 open geodes.
+//! ```
+//!
+//! However, blueprint 2 from the example above is still okay; the largest number of geodes you can open with it in 32 minutes is _`62`_.
+//!
+//! You _no longer have enough blueprints to worry about quality levels_.
+//! Instead, for each of the first three blueprints, determine the largest number of geodes you could open; then, multiply these three values together.
+//!
+//! Don't worry about quality levels; instead, just determine the largest number of geodes you could open using each of the first three blueprints.
+//! _What do you get if you multiply these numbers together?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(Inpt, Debug)]
+#[inpt(
+    regex = r"Blueprint (\d+): Each ore robot costs (\d+) ore\. Each clay robot costs (\d+) ore\. Each obsidian robot costs (\d+) ore and (\d+) clay\. Each geode robot costs (\d+) ore and (\d+) obsidian\."
+)]
+struct BlueprintScan {
+    id: u32,
+    ore_robot_ore: u32,
+    clay_robot_ore: u32,
+    obsidian_robot_ore: u32,
+    obsidian_robot_clay: u32,
+    geode_robot_ore: u32,
+    geode_robot_obsidian: u32,
+}
+
+#[derive(Debug, Clone)]
+struct Blueprint {
+    id: u32,
+    /// Cost in ore, clay and obsidian of each robot type, indexed by the resource it collects
+    costs: [[u32; 3]; 4],
+    /// Most of each resource which can be spent in a single minute.
+    /// Having more robots than that for a resource is pointless, as only one robot is built per minute.
+    max_spend: [u32; 3],
+}
+
+impl From<BlueprintScan> for Blueprint {
+    fn from(scan: BlueprintScan) -> Self {
+        let costs = [
+            [scan.ore_robot_ore, 0, 0],
+            [scan.clay_robot_ore, 0, 0],
+            [scan.obsidian_robot_ore, scan.obsidian_robot_clay, 0],
+            [scan.geode_robot_ore, 0, scan.geode_robot_obsidian],
+        ];
+        let max_spend = [0, 1, 2].map(|r| costs.iter().map(|cost| cost[r]).max().unwrap_or(0));
+        Self {
+            id: scan.id,
+            costs,
+            max_spend,
+        }
+    }
+}
+
+/// State of the search between two robot builds.
+///
+/// Geodes are counted up front: a geode robot built with `t` minutes left opens `t` geodes.
+#[derive(Debug, Clone, Copy)]
+struct State {
+    minutes_left: u32,
+    robots: [u32; 3],
+    resources: [u32; 3],
+    geodes: u32,
+}
+
+impl Blueprint {
+    /// Largest number of geodes which can be opened in `minutes`.
+    fn max_geodes(&self, minutes: u32) -> u32 {
+        let start = State {
+            minutes_left: minutes,
+            robots: [1, 0, 0],
+            resources: [0; 3],
+            geodes: 0,
+        };
+        let mut best = 0;
+        self.search(start, &mut best);
+        best
+    }
+
+    /// Branch on which robot to build next, skipping ahead to the minute it can be afforded.
+    fn search(&self, state: State, best: &mut u32) {
+        *best = (*best).max(state.geodes);
+        if self.upper_bound(&state) <= *best {
+            return;
+        }
+
+        // Try geode robots first, so that good solutions are found early and the bound prunes more
+        for robot in [GEODE, OBSIDIAN, CLAY, ORE] {
+            if robot != GEODE && state.robots[robot] >= self.max_spend[robot] {
+                continue;
+            }
+            let Some(wait) = self.minutes_until_affordable(&state, robot) else {
+                continue;
+            };
+            // The robot needs to be done with at least one minute left to collect anything
+            let Some(minutes_left) = state.minutes_left.checked_sub(wait + 1).filter(|&m| m > 0)
+            else {
+                continue;
+            };
+
+            let mut next = state;
+            next.minutes_left = minutes_left;
+            for r in 0..3 {
+                next.resources[r] =
+                    state.resources[r] + state.robots[r] * (wait + 1) - self.costs[robot][r];
+            }
+            if robot == GEODE {
+                next.geodes += minutes_left;
+            } else {
+                next.robots[robot] += 1;
+            }
+            self.search(next, best);
+        }
+    }
+
+    /// Minutes to wait before `robot` can be built, or `None` if no robot collects a required resource yet.
+    fn minutes_until_affordable(&self, state: &State, robot: usize) -> Option<u32> {
+        (0..3)
+            .map(|r| {
+                let missing = self.costs[robot][r].saturating_sub(state.resources[r]);
+                match (missing, state.robots[r]) {
+                    (0, _) => Some(0),
+                    (_, 0) => None,
+                    (missing, robots) => Some(missing.div_ceil(robots)),
+                }
+            })
+            .try_fold(0, |wait, r| Some(wait.max(r?)))
+    }
+
+    /// Optimistic number of geodes reachable from `state`.
+    ///
+    /// Ore and clay are treated as free and a new obsidian robot appears every minute.
+    /// A geode robot is built whenever there is enough obsidian for it.
+    fn upper_bound(&self, state: &State) -> u32 {
+        let cost = self.costs[GEODE][OBSIDIAN];
+        let mut obsidian = state.resources[OBSIDIAN];
+        let mut geodes = state.geodes;
+        let minutes = (0..state.minutes_left).rev();
+        for (obsidian_robots, minutes_left) in (state.robots[OBSIDIAN]..).zip(minutes) {
+            if obsidian >= cost {
+                obsidian -= cost;
+                geodes += minutes_left;
+            }
+            obsidian += obsidian_robots;
+        }
+        geodes
+    }
+}
+
+fn input_generator(input: &str) -> Result<Vec<Blueprint>> {
+    // Blueprints may be wrapped over several lines, like in the puzzle description
+    let input = input.split_whitespace().join(" ");
+    input
+        .split("Blueprint ")
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let blueprint = format!("Blueprint {}", s.trim());
+            inpt::<BlueprintScan>(&blueprint)
+                .map(Blueprint::from)
+                .map_err(|err| anyhow!("Invalid blueprint {blueprint:?}: {err}"))
+        })
+        .collect()
+}
+
+fn part1(input: &[Blueprint]) -> u32 {
+    input
+        .par_iter()
+        .map(|blueprint| blueprint.id * blueprint.max_geodes(24))
+        .sum()
+}
+
+fn part2(input: &[Blueprint]) -> u32 {
+    input[..input.len().min(3)]
+        .par_iter()
+        .map(|blueprint| blueprint.max_geodes(32))
+        .product()
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day19>();
+
+struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    const DAY: u32 = 19;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_parse() -> Result<()> {
+    let example = example_input(19, Part::One, 0)?;
+    let values = input_generator(&example)?;
+    assert_eq!(2, values.len());
+    assert_eq!([[4, 0, 0], [2, 0, 0], [3, 14, 0], [2, 0, 7]], values[0].costs);
+    assert_eq!([4, 14, 7], values[0].max_spend);
+    // One blueprint per line, like the actual input
+    let oneline = example.split("\n\n").map(|b| b.split_whitespace().join(" ")).join("\n");
+    assert_eq!(values[1].costs, input_generator(&oneline)?[1].costs);
+    Ok(())
+}
+
+#[test]
+fn test_max_geodes() -> Result<()> {
+    let values = input_generator(&example_input(19, Part::One, 0)?)?;
+    assert_eq!(9, values[0].max_geodes(24));
+    assert_eq!(12, values[1].max_geodes(24));
+    Ok(())
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    // The docs only emphasize the geodes of each blueprint, not their product
+    let values = input_generator(&example_input(19, Part::One, 0)?)?;
+    assert_eq!(56 * 62, part2(&values));
+    Ok(())
+}
@@ -0,0 +1,334 @@
+//! # Day 20: Grove Positioning System
+//!
+//! ## --- Part One ---
+//!
+//! It's finally time to meet back up with the Elves.
+//! When you try to contact them, however, you get no reply.
+//! Perhaps you're out of range?
+//!
+//! You know they're headed to the grove where the _star_ fruit grows, so if you can figure out where that is, you should be able to meet back up with them.
+//!
+//! Fortunately, your handheld device has a file (your puzzle input) that contains the grove's coordinates!
+//! Unfortunately, the file is _encrypted_ - just in case the device were to fall into the wrong hands.
+//!
+//! Maybe you can decrypt it?
+//!
+//! When you were still back at the camp, you overheard some Elves talking about coordinate file encryption.
+//! The main operation involved in decrypting the file is called _mixing_.
+//!
+//! The encrypted file is a list of numbers.
+//! To _mix_ the file, move each number forward or backward in the file a number of positions equal to the value of the number being moved.
+//! The list is _circular_, so moving a number off one end of the list wraps back around to the other end as if the ends were connected.
+//!
+//! For example, to move the `1` in a sequence like `4, 5, 6, 1, 7, 8, 9`, the `1` moves one position forward: `4, 5, 6, 7, 1, 8, 9`.
+//! To move the `-2` in a sequence like `4, -2, 5, 6, 7, 8, 9`, the `-2` moves two positions backward, wrapping around: `4, 5, 6, 7, 8, -2, 9`.
+//!
+//! The numbers should be moved _in the order they originally appear_ in the encrypted file.
+//! Numbers moving around during the mixing process do not change the order in which the numbers are moved.
+//!
+//! Consider this encrypted file:
+//!
+//! ```text
+//! 1
+//! 2
+//! -3
+//! 3
+//! -2
+//! 0
+//! 4
+//! ```
+//!
+//! Mixing this file proceeds as follows:
+//!
+//! ```text
+//! Initial arrangement:
+//! 1, 2, -3, 3, -2, 0, 4
+//!
+//! 1 moves between 2 and -3:
+//! 2, 1, -3, 3, -2, 0, 4
+//!
+//! 2 moves between -3 and 3:
+//! 1, -3, 2, 3, -2, 0, 4
+//!
+//! -3 moves between -2 and 0:
+//! 1, 2, 3, -2, -3, 0, 4
+//!
+//! 3 moves between 0 and 4:
+//! 1, 2, -2, -3, 0, 3, 4
+//!
+//! -2 moves between 4 and 1:
+//! 1, 2, -3, 0, 3, 4, -2
+//!
+//! 0 does not move:
+//! 1, 2, -3, 0, 3, 4, -2
+//!
+//! 4 moves between -3 and 0:
+//! 1, 2, -3, 4, 0, 3, -2
+//! ```
+//!
+//! Then, the grove coordinates can be found by looking at the 1000th, 2000th, and 3000th numbers after the value `0`, wrapping around the list as necessary.
+//! In the above example, the 1000th number after `0` is _`4`_, the 2000th is _`-3`_, and the 3000th is _`2`_; adding these together produces _`3`_.
+//!
+//! Mix your encrypted file exactly once.
+//! _What is the sum of the three numbers that form the grove coordinates?_
+//!
+//! ## --- Part Two ---
+//!
+//! The grove coordinate values seem nonsensical.
+//! While you ponder the mysteries of Elf encryption, you suddenly remember the rest of the decryption routine you overheard back at camp.
+//!
+//! First, you need to apply the _decryption key_, `811589153`.
+//! Multiply each number by the decryption key before you begin; this will produce the actual list of numbers to mix.
+//!
+//! Second, you need to mix the list of numbers _ten times_.
+//! The order in which the numbers are mixed does not change during mixing; the numbers are still moved in the order they appeared in the original, pre-mixed list.
+//! (So, if -3 appears fourth in the original list of numbers to mix, -3 will be the fourth number to move during each round of mixing.)
+//!
+//! Using the same example as above:
+//!
+//! ```text
+//! Initial arrangement:
+//! 811589153, 1623178306, -2434767459, 2434767459, -1623178306, 0, 3246356612
+//!
+//! After 1 round of mixing:
+//! 0, -2434767459, 3246356612, -1623178306, 2434767459, 1623178306, 811589153
+//!
+//! After 2 rounds of mixing:
+//! 0, 2434767459, 1623178306, 3246356612, -2434767459, -1623178306, 811589153
+//!
+//! After 3 rounds of mixing:
+//! 0, 811589153, 2434767459, 3246356612, 1623178306, -1623178306, -2434767459
+//!
+//! After 4 rounds of mixing:
+//! 0, 1623178306, -2434767459, 811589153, 2434767459, 3246356612, -1623178306
+//!
+//! After 5 rounds of mixing:
+//! 0, 811589153, -1623178306, 1623178306, -2434767459, 3246356612, 2434767459
+//!
+//! After 6 rounds of mixing:
+//! 0, 811589153, -1623178306, 3246356612, -2434767459, 1623178306, 2434767459
+//!
+//! After 7 rounds of mixing:
+//! 0, -2434767459, 2434767459, 1623178306, -1623178306, 811589153, 3246356612
+//!
+//! After 8 rounds of mixing:
+//! 0, 1623178306, 3246356612, 811589153, -2434767459, 2434767459, -1623178306
+//!
+//! After 9 rounds of mixing:
+//! 0, 811589153, 1623178306, -2434767459, 3246356612, 2434767459, -1623178306
+//!
+//! After 10 rounds of mixing:
+//! 0, -2434767459, 1623178306, 3246356612, -1623178306, 2434767459, 811589153
+//! ```
+//!
+//! The grove coordinates can still be found in the same way.
+//! Here, the 1000th number after `0` is _`811589153`_, the 2000th is _`2434767459`_, and the 3000th is _`-1623178306`_; adding these together produces _`1623178306`_.
+//!
+//! Apply the decryption key and mix your encrypted file ten times.
+//! _What is the sum of the three numbers that form the grove coordinates?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+/// A circular arrangement of the indices into the original file, split into blocks.
+///
+/// Finding, removing and inserting an index only touches one block and the block lengths,
+/// so each move costs about `O(sqrt n)` instead of `O(n)`.
+/// Indices are unique even when the values are not, so duplicates are no problem.
+#[derive(Debug, Clone)]
+struct Arrangement {
+    blocks: Vec<Vec<usize>>,
+    /// Block currently holding each index
+    block_of: Vec<usize>,
+    block_size: usize,
+}
+
+impl Arrangement {
+    fn new(len: usize) -> Self {
+        let block_size = (len as f64).sqrt().ceil().max(1.0) as usize;
+        let mut arrangement = Self {
+            blocks: vec![(0..len).collect()],
+            block_of: vec![0; len],
+            block_size,
+        };
+        arrangement.rebalance();
+        arrangement
+    }
+
+    fn len(&self) -> usize {
+        self.block_of.len()
+    }
+
+    /// Split everything into evenly sized blocks again.
+    fn rebalance(&mut self) {
+        let order = self.blocks.concat();
+        self.blocks = order.chunks(self.block_size).map(<[_]>::to_vec).collect();
+        for (block, indices) in self.blocks.iter().enumerate() {
+            for &idx in indices {
+                self.block_of[idx] = block;
+            }
+        }
+    }
+
+    /// Remove `idx` and return the position it was at.
+    fn remove(&mut self, idx: usize) -> usize {
+        let block = self.block_of[idx];
+        let offset = self.blocks[block].iter().position(|&i| i == idx).unwrap();
+        self.blocks[block].remove(offset);
+        self.blocks[..block].iter().map(Vec::len).sum::<usize>() + offset
+    }
+
+    /// Insert `idx` so that it ends up at `position`.
+    fn insert(&mut self, idx: usize, mut position: usize) {
+        let mut block = 0;
+        while block + 1 < self.blocks.len() && position > self.blocks[block].len() {
+            position -= self.blocks[block].len();
+            block += 1;
+        }
+        self.blocks[block].insert(position, idx);
+        self.block_of[idx] = block;
+        if self.blocks[block].len() > 2 * self.block_size {
+            self.rebalance();
+        }
+    }
+
+    /// Move the number at `idx` by `value` positions.
+    fn shift(&mut self, idx: usize, value: i64) {
+        let position = self.remove(idx);
+        // The other numbers form a cycle of length n - 1
+        let others = self.len() as i64 - 1;
+        let position = (position as i64 + value).rem_euclid(others.max(1));
+        self.insert(idx, position as usize);
+    }
+
+    fn order(&self) -> impl Iterator<Item = usize> + '_ {
+        self.blocks.iter().flatten().copied()
+    }
+}
+
+/// Mix the numbers `rounds` times and return them in their final order.
+fn mix(numbers: &[i64], rounds: usize) -> Vec<i64> {
+    let mut arrangement = Arrangement::new(numbers.len());
+    for _ in 0..rounds {
+        for (idx, &value) in numbers.iter().enumerate() {
+            arrangement.shift(idx, value);
+        }
+    }
+    arrangement.order().map(|idx| numbers[idx]).collect()
+}
+
+fn grove_coordinates(mixed: &[i64]) -> Result<i64> {
+    let zero = mixed
+        .iter()
+        .position(|&v| v == 0)
+        .ok_or_else(|| anyhow!("No 0 in the file"))?;
+    Ok([1000, 2000, 3000]
+        .iter()
+        .map(|offset| mixed[(zero + offset) % mixed.len()])
+        .sum())
+}
+
+fn input_generator(input: &str) -> Result<Vec<i64>> {
+    input
+        .lines()
+        .map(|line| line.parse().map_err(|err| anyhow!("Invalid number {line:?}: {err}")))
+        .collect()
+}
+
+fn part1(input: &[i64]) -> Result<i64> {
+    grove_coordinates(&mix(input, 1))
+}
+
+fn part2(input: &[i64]) -> Result<i64> {
+    let numbers = input.iter().map(|v| v * DECRYPTION_KEY).collect_vec();
+    grove_coordinates(&mix(&numbers, 10))
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day20>();
+
+struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+
+    const DAY: u32 = 20;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_mix() -> Result<()> {
+    let values = input_generator(&example_input(20, Part::One, 0)?)?;
+    // Same cycle as `1, 2, -3, 4, 0, 3, -2`, starting at a different point
+    let mixed = mix(&values, 1);
+    let start = mixed.iter().position(|&v| v == 1).unwrap();
+    let rotated = mixed[start..].iter().chain(&mixed[..start]).copied().collect_vec();
+    assert_eq!(vec![1, 2, -3, 4, 0, 3, -2], rotated);
+    Ok(())
+}
+
+#[test]
+fn test_mix_duplicates() {
+    // Compare against moving the numbers around in a plain `Vec`
+    let numbers = (0..500_i64).map(|i| (i * 7919) % 23 - 11).collect_vec();
+    let mut naive = (0..numbers.len()).collect_vec();
+    for _ in 0..3 {
+        for (idx, &value) in numbers.iter().enumerate() {
+            let position = naive.iter().position(|&i| i == idx).unwrap();
+            naive.remove(position);
+            let position = (position as i64 + value).rem_euclid(naive.len() as i64);
+            naive.insert(position as usize, idx);
+        }
+    }
+    let naive = naive.into_iter().map(|idx| numbers[idx]).collect_vec();
+    let mixed = mix(&numbers, 3);
+    assert_eq!(grove_coordinates(&naive).unwrap(), grove_coordinates(&mixed).unwrap());
+    assert!((0..mixed.len()).any(|start| mixed[start..]
+        .iter()
+        .chain(&mixed[..start])
+        .eq(&naive)));
+}
+
+#[test]
+fn test_mix_rounds() -> Result<()> {
+    let values = input_generator(&example_input(20, Part::One, 0)?)?;
+    let numbers = values.iter().map(|v| v * DECRYPTION_KEY).collect_vec();
+    let walkthrough = example_input(20, Part::Two, 0)?;
+    // Every arrangement is drawn starting at the `0`, except for the initial one
+    for (rounds, (_, line)) in walkthrough
+        .lines()
+        .filter(|line| !line.is_empty())
+        .tuples()
+        .enumerate()
+    {
+        let expected: Vec<i64> = line.split(", ").map(str::parse).try_collect()?;
+        let mixed = mix(&numbers, rounds);
+        let start = if rounds == 0 {
+            0
+        } else {
+            mixed.iter().position(|&v| v == 0).unwrap()
+        };
+        let rotated = mixed[start..]
+            .iter()
+            .chain(&mixed[..start])
+            .copied()
+            .collect_vec();
+        assert_eq!(expected, rotated, "after {rounds} rounds");
+    }
+    Ok(())
+}
@@ -0,0 +1,356 @@
+//! # Day 21: Monkey Math
+//!
+//! ## --- Part One ---
+//!
+//! The [monkeys](https://adventofcode.com/2022/day/11) are back!
+//! You're worried they're going to try to steal your stuff again, but it seems like they're just holding their ground and making various monkey noises at you.
+//!
+//! Eventually, one of the elephants realizes you don't speak monkey and comes over to interpret.
+//! As it turns out, they overheard you talking about trying to find the grove; they can show you a shortcut if you answer their _riddle_.
+//!
+//! Each monkey is given a _job_: either to _yell a specific number_ or to _yell the result of a math operation_.
+//! All of the number-yelling monkeys know their number from the start; however, the math operation monkeys need to wait for two other monkeys to yell a number, and those two other monkeys might _also_ be waiting on other monkeys.
+//!
+//! Your job is to _work out the number the monkey named `root` will yell_ before the monkeys figure it out themselves.
+//!
+//! For example:
+//!
+//! ```text
+//! root: pppw + sjmn
+//! dbpl: 5
+//! cczh: sllz + lgvd
+//! zczc: 2
+//! ptdq: humn - dvpt
+//! dvpt: 3
+//! lfqf: 4
+//! humn: 5
+//! ljgn: 2
+//! sjmn: drzm * dbpl
+//! sllz: 4
+//! pppw: cczh / lfqf
+//! lgvd: ljgn * ptdq
+//! drzm: hmdt - zczc
+//! hmdt: 32
+//! ```
+//!
+//! Each line contains the name of a monkey, a colon, and then the job of that monkey:
+//!
+//! - A lone number means the monkey's job is simply to yell that number.
+//! - A job like `aaaa + bbbb` means the monkey waits for monkeys `aaaa` and `bbbb` to yell each of their numbers; the monkey then yells the sum of those two numbers.
+//! - `aaaa - bbbb` means the monkey yells `aaaa`'s number minus `bbbb`'s number.
+//! - Job `aaaa * bbbb` will yell `aaaa`'s number multiplied by `bbbb`'s number.
+//! - Job `aaaa / bbbb` will yell `aaaa`'s number divided by `bbbb`'s number.
+//!
+//! So, in the above example, monkey `drzm` has to wait for monkeys `hmdt` and `zczc` to yell their numbers.
+//! Fortunately, both `hmdt` and `zczc` have jobs that involve simply yelling a single number, so they do this immediately: `32` and `2`.
+//! Monkey `drzm` can then yell its number by finding `32` minus `2`: _`30`_.
+//!
+//! Then, monkey `sjmn` has one of its numbers (`30`, from monkey `drzm`), and already has its other number, `5`, from `dbpl`.
+//! This allows it to yell its own number by finding `30` multiplied by `5`: _`150`_.
+//!
+//! This process continues until `root` yells a number: _`152`_.
+//!
+//! However, your actual situation involves considerably more monkeys.
+//! _What number will the monkey named `root` yell?_
+//!
+//! ## --- Part Two ---
+//!
+//! Due to some kind of monkey-elephant-human mistranslation, you seem to have misunderstood a few key details about the riddle.
+//!
+//! First, you got the wrong job for the monkey named `root`; specifically, you got the wrong math operation.
+//! The correct operation for monkey `root` should be `=`, which means that it still listens for two numbers (from the same two monkeys as before), but now checks that the two numbers _match_.
+//!
+//! Second, you got the wrong monkey for the job starting with `humn:`.
+//! It isn't a monkey - it's _you_.
+//! Actually, you got the job wrong, too: you need to figure out _what number you need to yell_ so that `root`'s equality check passes.
+//! (The number that appears after `humn:` in your input is now irrelevant.)
+//!
+//! In the above example, the number you need to yell to pass `root`'s equality test is _`301`_.
+//! (This causes `root` to get the same number, `150`, from both of its monkeys.)
+//!
+//! _What number do you yell to pass `root`'s equality test?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+/// Index of a monkey in [`Monkeys`]
+pub type NodeId = usize;
+
+/// Operation of a monkey which waits for two other monkeys
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl FromStr for Op {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Op::Add),
+            "-" => Ok(Op::Sub),
+            "*" => Ok(Op::Mul),
+            "/" => Ok(Op::Div),
+            _ => Err(anyhow!("Invalid operation: {}", s)),
+        }
+    }
+}
+
+impl Op {
+    fn apply(self, lhs: i64, rhs: i64) -> Result<i64> {
+        let result = match self {
+            Op::Add => lhs.checked_add(rhs),
+            Op::Sub => lhs.checked_sub(rhs),
+            Op::Mul => lhs.checked_mul(rhs),
+            Op::Div => exact_div(lhs, rhs),
+        };
+        result.ok_or_else(|| anyhow!("No integer result for {lhs} {self:?} {rhs}"))
+    }
+}
+
+/// Division which only succeeds if there is no remainder.
+fn exact_div(lhs: i64, rhs: i64) -> Option<i64> {
+    (rhs != 0 && lhs % rhs == 0).then(|| lhs / rhs)
+}
+
+/// What a single monkey yells
+#[derive(Debug, Clone)]
+pub enum Job {
+    Number(i64),
+    Operation(NodeId, Op, NodeId),
+}
+
+/// All monkey jobs as an expression DAG, with the monkeys interned into node ids.
+///
+/// Parsing fails if a monkey (indirectly) waits for its own number, so the jobs never form a cycle.
+#[derive(Debug, Clone)]
+pub struct Monkeys {
+    jobs: Vec<Job>,
+    ids: HashMap<String, NodeId>,
+}
+
+impl FromStr for Monkeys {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s
+            .lines()
+            .map(|line| {
+                line.split_once(": ")
+                    .ok_or_else(|| anyhow!("Invalid monkey: {}", line))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let ids: HashMap<String, NodeId> = lines
+            .iter()
+            .enumerate()
+            .map(|(id, (name, _))| (name.to_string(), id))
+            .collect();
+        let lookup = |name: &str| {
+            ids.get(name)
+                .copied()
+                .ok_or_else(|| anyhow!("Unknown monkey: {}", name))
+        };
+
+        let jobs = lines
+            .iter()
+            .map(|(name, job)| match job.split(' ').collect_vec()[..] {
+                [number] => Ok(Job::Number(number.parse()?)),
+                [lhs, op, rhs] => Ok(Job::Operation(lookup(lhs)?, op.parse()?, lookup(rhs)?)),
+                _ => Err(anyhow!("Invalid job for {}: {}", name, job)),
+            })
+            .collect::<Result<_>>()?;
+        let monkeys = Self { jobs, ids };
+        if let Some(node) = monkeys.find_cycle() {
+            return Err(anyhow!("Monkey {} waits for its own number", lines[node].0));
+        }
+        Ok(monkeys)
+    }
+}
+
+impl Monkeys {
+    pub fn id(&self, name: &str) -> Result<NodeId> {
+        self.ids
+            .get(name)
+            .copied()
+            .ok_or_else(|| anyhow!("Unknown monkey: {}", name))
+    }
+
+    /// A monkey which (indirectly) waits for its own number, found by a depth-first search.
+    fn find_cycle(&self) -> Option<NodeId> {
+        // `Some(false)` while visiting the dependencies of a monkey, `Some(true)` once done
+        let mut visited = vec![None; self.jobs.len()];
+        (0..self.jobs.len()).find_map(|node| self.visit(node, &mut visited))
+    }
+
+    fn visit(&self, node: NodeId, visited: &mut [Option<bool>]) -> Option<NodeId> {
+        match visited[node] {
+            Some(true) => return None,
+            Some(false) => return Some(node),
+            None => {}
+        }
+        visited[node] = Some(false);
+        if let Job::Operation(lhs, _, rhs) = self.jobs[node] {
+            let cycle = self
+                .visit(lhs, visited)
+                .or_else(|| self.visit(rhs, visited));
+            if cycle.is_some() {
+                return cycle;
+            }
+        }
+        visited[node] = Some(true);
+        None
+    }
+
+    /// The number the monkey `node` yells.
+    pub fn eval(&self, node: NodeId) -> Result<i64> {
+        match self.jobs[node] {
+            Job::Number(n) => Ok(n),
+            Job::Operation(lhs, op, rhs) => op.apply(self.eval(lhs)?, self.eval(rhs)?),
+        }
+    }
+
+    /// Whether the number of `node` depends on the number of `unknown`.
+    pub fn depends_on(&self, node: NodeId, unknown: NodeId) -> bool {
+        node == unknown
+            || match self.jobs[node] {
+                Job::Number(_) => false,
+                Job::Operation(lhs, _, rhs) => {
+                    self.depends_on(lhs, unknown) || self.depends_on(rhs, unknown)
+                }
+            }
+    }
+
+    /// Find the number `unknown` has to yell so that `node` yells `target`.
+    ///
+    /// Walks down the path from `node` to `unknown`, inverting each operation on the way.
+    /// Every other branch is evaluated normally, so `unknown` may only appear on one side of each operation.
+    pub fn solve(&self, node: NodeId, unknown: NodeId, target: i64) -> Result<i64> {
+        if node == unknown {
+            return Ok(target);
+        }
+        let Job::Operation(lhs, op, rhs) = self.jobs[node] else {
+            return Err(anyhow!("Node {node} does not depend on the unknown"));
+        };
+        let (in_lhs, in_rhs) = (self.depends_on(lhs, unknown), self.depends_on(rhs, unknown));
+        let no_solution = || anyhow!("No integer solution for node {node} = {target}");
+
+        match (in_lhs, in_rhs) {
+            // target = x op known
+            (true, false) => {
+                let known = self.eval(rhs)?;
+                let x = match op {
+                    Op::Add => target.checked_sub(known),
+                    Op::Sub => target.checked_add(known),
+                    Op::Mul => exact_div(target, known),
+                    Op::Div => target.checked_mul(known),
+                };
+                self.solve(lhs, unknown, x.ok_or_else(no_solution)?)
+            }
+            // target = known op x
+            (false, true) => {
+                let known = self.eval(lhs)?;
+                let x = match op {
+                    Op::Add => target.checked_sub(known),
+                    Op::Sub => known.checked_sub(target),
+                    Op::Mul => exact_div(target, known),
+                    Op::Div => exact_div(known, target),
+                };
+                self.solve(rhs, unknown, x.ok_or_else(no_solution)?)
+            }
+            (true, true) => Err(anyhow!("The unknown appears on both sides of node {node}")),
+            (false, false) => Err(anyhow!("Node {node} does not depend on the unknown")),
+        }
+    }
+
+    /// Find the number `unknown` has to yell so that both sides of `node` yell the same number.
+    pub fn solve_equality(&self, node: NodeId, unknown: NodeId) -> Result<i64> {
+        let Job::Operation(lhs, _, rhs) = self.jobs[node] else {
+            return Err(anyhow!("Node {node} has no two sides to compare"));
+        };
+        if self.depends_on(lhs, unknown) {
+            self.solve(lhs, unknown, self.eval(rhs)?)
+        } else {
+            self.solve(rhs, unknown, self.eval(lhs)?)
+        }
+    }
+}
+
+fn input_generator(input: &str) -> Result<Monkeys> {
+    input.parse()
+}
+
+fn part1(input: &Monkeys) -> Result<i64> {
+    input.eval(input.id(ROOT)?)
+}
+
+fn part2(input: &Monkeys) -> Result<i64> {
+    input.solve_equality(input.id(ROOT)?, input.id(HUMAN)?)
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day21>();
+
+struct Day21;
+
+impl Solution for Day21 {
+    type Input = Monkeys;
+
+    const DAY: u32 = 21;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_solve() -> Result<()> {
+    let values = input_generator(&example_input(21, Part::One, 0)?)?;
+    let (pppw, humn) = (values.id("pppw")?, values.id(HUMAN)?);
+    assert_eq!(301, values.solve(pppw, humn, 150)?);
+    // Not reachable through humn at all
+    assert!(values.solve(values.id("sjmn")?, humn, 150).is_err());
+    // The unknown on the right-hand side of a subtraction and a division
+    let values: Monkeys = "root: a / b\na: 100\nb: c - humn\nc: 30\nhumn: 0".parse()?;
+    assert_eq!(26, values.solve(values.id(ROOT)?, values.id(HUMAN)?, 25)?);
+    // 100 / 7 is no integer, so neither is humn
+    assert!(values
+        .solve(values.id(ROOT)?, values.id(HUMAN)?, 7)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_eval() -> Result<()> {
+    let values = input_generator(&example_input(21, Part::One, 0)?)?;
+    assert_eq!(30, values.eval(values.id("drzm")?)?);
+    assert_eq!(150, values.eval(values.id("sjmn")?)?);
+    Ok(())
+}
+
+#[test]
+fn test_cycle() {
+    let cycle = "root: a + b\na: b * c\nb: c - a\nc: 2".parse::<Monkeys>();
+    assert_eq!(
+        "Monkey a waits for its own number",
+        cycle.unwrap_err().to_string()
+    );
+    assert!("root: root + a\na: 1".parse::<Monkeys>().is_err());
+    // Waiting for the same monkey twice is no cycle
+    assert!("root: a + a\na: 1".parse::<Monkeys>().is_ok());
+}
@@ -0,0 +1,540 @@
+//! # Day 22: Monkey Map
+//!
+//! ## --- Part One ---
+//!
+//! The monkeys take you on a surprisingly easy trail through the jungle.
+//! They're even going in roughly the right direction according to your handheld device's Grove Positioning System.
+//!
+//! As you walk, the monkeys explain that the grove is protected by a _force field_.
+//! To pass through the force field, you have to enter a password; doing so involves tracing a specific _path_ on a strangely-shaped board.
+//!
+//! At least, you're pretty sure that's what you have to do; the elephants aren't exactly fluent in monkey.
+//!
+//! The monkeys give you notes that they took when they last saw the password entered (your puzzle input).
+//!
+//! For example:
+//!
+//! ```text
+//!         ...#
+//!         .#..
+//!         #...
+//!         ....
+//! ...#.......#
+//! ........#...
+//! ..#....#....
+//! ..........#.
+//!         ...#....
+//!         .....#..
+//!         .#......
+//!         ......#.
+//!
+//! 10R5L5R10L4R5L5
+//! ```
+//!
+//! The first half of the monkeys' notes is a _map of the board_.
+//! It is comprised of a set of _open tiles_ (on which you can move, drawn `.`) and _solid walls_ (tiles which you cannot enter, drawn `#`).
+//!
+//! The second half is a description of _the path you must follow_.
+//! It consists of alternating numbers and letters:
+//!
+//! - A _number_ indicates the _number of tiles to move_ in the direction you are facing.
+//!   If you run into a wall, you stop moving forward and continue with the next instruction.
+//! - A _letter_ indicates whether to turn 90 degrees _clockwise_ (`R`) or _counterclockwise_ (`L`).
+//!   Turning happens in-place; it does not change your current tile.
+//!
+//! So, a path like `10R5` means "go forward 10 tiles, then turn clockwise 90 degrees, then go forward 5 tiles".
+//!
+//! You begin the path in the leftmost open tile of the top row of tiles.
+//! Initially, you are facing _to the right_ (from the perspective of how the map is drawn).
+//!
+//! If a movement instruction would take you off of the map, you _wrap around_ to the other side of the board.
+//! In other words, if your next tile is off of the board, you should instead look in the direction opposite of your current facing as far as you can until you find the opposite edge of the board, then reappear there.
+//!
+//! For example, if you are at `A` and facing to the right, the tile in front of you is marked `B`; if you are at `C` and facing down, the tile in front of you is marked `D`:
+//!
+//! ```text
+//!         ...#
+//!         .#..
+//!         #...
+//!         ....
+//! ...#.D.....#
+//! ........#...
+//! B.#....#...A
+//! .....C....#.
+//!         ...#....
+//!         .....#..
+//!         .#......
+//!         ......#.
+//! ```
+//!
+//! It is possible for the next tile (after wrapping around) to be a _wall_; this still counts as there being a wall in front of you, and so movement stops before you actually wrap to the other side of the board.
+//!
+//! By drawing the _last facing you had_ with an arrow on each tile you visit, the full path taken by the above example looks like this:
+//!
+//! ```text
+//!         >>v#
+//!         .#v.
+//!         #.v.
+//!         ..v.
+//! ...#...v..v#
+//! >>>v...>#.>>
+//! ..#v...#....
+//! ...>>>>v..#.
+//!         ...#....
+//!         .....#..
+//!         .#......
+//!         ......#.
+//! ```
+//!
+//! To finish providing the password to this strange input device, you need to determine numbers for your final _row_, _column_, and _facing_ as your final position appears from the perspective of the original map.
+//! Rows start from `1` at the top and count downward; columns start from `1` at the left and count rightward.
+//! Facing is `0` for right (`>`), `1` for down (`v`), `2` for left (`<`), and `3` for up (`^`).
+//! The _final password_ is the sum of 1000 times the row, 4 times the column, and the facing.
+//!
+//! In the above example, the final row is `6`, the final column is `8`, and the final facing is `0`.
+//! So, the final password is 1000 * 6 + 4 * 8 + 0: _`6032`_.
+//!
+//! Follow the path given in the monkeys' notes.
+//! _What is the final password?_
+//!
+//! ## --- Part Two ---
+//!
+//! As you reach the force field, you think you hear some Elves in the distance.
+//! Perhaps they've already arrived?
+//!
+//! You approach the strange input device, but it isn't quite what the monkeys drew in their notes.
+//! Instead, you are met with a large _cube_; each of its six faces is a square of 50x50 tiles.
+//!
+//! To be fair, the monkeys' map _does_ have six 50x50 regions on it.
+//! If you were to _carefully fold the map_, you should be able to shape it into a cube!
+//!
+//! In the example above, the six (smaller, 4x4) faces of the cube are:
+//!
+//! ```text
+//!         1111
+//!         1111
+//!         1111
+//!         1111
+//! 222233334444
+//! 222233334444
+//! 222233334444
+//! 222233334444
+//!         55556666
+//!         55556666
+//!         55556666
+//!         55556666
+//! ```
+//!
+//! You still start in the same position and with the same facing as before, but the _wrapping_ rules are different.
+//! Now, if you would walk off the board, you instead _proceed around the cube_.
+//! From the perspective of the map, this can look a little strange.
+//! In the above example, if you are at `A` and move to the right, you would arrive at `B` facing down; if you are at `C` and move down, you would arrive at `D` facing up:
+//!
+//! ```text
+//!         ...#
+//!         .#..
+//!         #...
+//!         ....
+//! ...#.......#
+//! ........#..A
+//! ..#....#....
+//! .D........#.
+//!         ...#..B.
+//!         .....#..
+//!         .#......
+//!         ..C...#.
+//! ```
+//!
+//! Walls still block your path, even if they are on a different face of the cube.
+//! If you are at `E` facing up, your movement is blocked by the wall marked by the arrow:
+//!
+//! ```text
+//!         ...#
+//!         .#..
+//!      -->#...
+//!         ....
+//! ...#..E....#
+//! ........#...
+//! ..#....#....
+//! ..........#.
+//!         ...#....
+//!         .....#..
+//!         .#......
+//!         ......#.
+//! ```
+//!
+//! Using the same method of drawing the _last facing you had_ with an arrow on each tile you visit, the full path taken by the above example now looks like this:
+//!
+//! ```text
+//!         >>v#
+//!         .#v.
+//!         #.v.
+//!         ..v.
+//! ...#..^...v#
+//! .>>>>>^.#.>>
+//! .^#....#....
+//! .^........#.
+//!         ...#..v.
+//!         .....#v.
+//!         .#v<<<<.
+//!         ..v...#.
+//! ```
+//!
+//! The final password is still calculated from your final position and facing from the perspective of the map.
+//! In this example, the final row is `5`, the final column is `7`, and the final facing is `3`, so the final password is 1000 * 5 + 4 * 7 + 3 = _`5031`_.
+//!
+//! Fold the map into a cube, _then_ follow the path given in the monkeys' notes.
+//! _What is the final password?_
+
+use crate::grid::Grid;
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+type Pos = (usize, usize);
+type Vec3 = [i32; 3];
+
+/// Steps for each facing, in the order used by the password: right, down, left, up
+const DIRECTIONS: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Step {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+struct Board {
+    tiles: Grid<Tile>,
+    path: Vec<Step>,
+}
+
+impl FromStr for Board {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (map, path) = s
+            .split_once("\n\n")
+            .ok_or_else(|| anyhow!("Missing empty line between map and path"))?;
+
+        // Lines are not padded to the full width of the board
+        let width = map.lines().map(str::len).max().unwrap_or(0);
+        let map = map.lines().map(|line| format!("{line:width$}")).join("\n");
+        let tiles = Grid::parse(&map, |c| match c {
+            ' ' => Ok(Tile::Void),
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            _ => Err(anyhow!("Invalid tile: {}", c)),
+        })?;
+
+        let mut steps = Vec::new();
+        let mut number = None;
+        for c in path.trim().chars() {
+            if let Some(digit) = c.to_digit(10) {
+                number = Some(number.unwrap_or(0) * 10 + digit as usize);
+                continue;
+            }
+            if let Some(n) = number.take() {
+                steps.push(Step::Forward(n));
+            }
+            steps.push(match c {
+                'L' => Step::Left,
+                'R' => Step::Right,
+                _ => return Err(anyhow!("Invalid step: {}", c)),
+            });
+        }
+        steps.extend(number.map(Step::Forward));
+
+        Ok(Self { tiles, path: steps })
+    }
+}
+
+impl Board {
+    /// The next tile in `dir`, if it is on the board.
+    fn next(&self, (x, y): Pos, dir: usize) -> Option<Pos> {
+        let (dx, dy) = DIRECTIONS[dir];
+        let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.tiles
+            .get(next)
+            .is_some_and(|&t| t != Tile::Void)
+            .then_some(next)
+    }
+
+    /// Wrap around to the opposite edge of the board in the same row or column.
+    fn flat_wrap(&self, mut pos: Pos, dir: usize) -> (Pos, usize) {
+        while let Some(prev) = self.next(pos, (dir + 2) % 4) {
+            pos = prev;
+        }
+        (pos, dir)
+    }
+
+    /// Follow the path and return the final password.
+    ///
+    /// `wrap` gives the tile and facing after walking off the board.
+    fn walk(&self, wrap: impl Fn(Pos, usize) -> (Pos, usize)) -> Result<usize> {
+        let mut pos = self
+            .tiles
+            .position(|&t| t == Tile::Open)
+            .ok_or_else(|| anyhow!("No open tile on the board"))?;
+        let mut dir = 0;
+
+        for step in &self.path {
+            match *step {
+                Step::Left => dir = (dir + 3) % 4,
+                Step::Right => dir = (dir + 1) % 4,
+                Step::Forward(n) => {
+                    for _ in 0..n {
+                        let (next, next_dir) = match self.next(pos, dir) {
+                            Some(next) => (next, dir),
+                            None => wrap(pos, dir),
+                        };
+                        if self.tiles[next] == Tile::Wall {
+                            break;
+                        }
+                        (pos, dir) = (next, next_dir);
+                    }
+                }
+            }
+        }
+
+        let (x, y) = pos;
+        Ok(1000 * (y + 1) + 4 * (x + 1) + dir)
+    }
+}
+
+fn neg(v: Vec3) -> Vec3 {
+    v.map(|c| -c)
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(v: Vec3, k: i32) -> Vec3 {
+    v.map(|c| c * k)
+}
+
+fn dot(a: Vec3, b: Vec3) -> i32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// A face of the folded cube.
+///
+/// The axes are unit vectors in 3D, with the cube centered on the origin.
+#[derive(Debug, Clone)]
+struct Face {
+    /// Top left tile of the face on the map
+    origin: Pos,
+    /// Outward normal
+    normal: Vec3,
+    /// Direction of increasing `x` on the map
+    right: Vec3,
+    /// Direction of increasing `y` on the map
+    down: Vec3,
+}
+
+/// The board folded into a cube.
+#[derive(Debug, Clone)]
+struct Cube {
+    size: usize,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    /// Fold the net on the board by walking from face to face and bending each edge by 90 degrees.
+    fn fold(tiles: &Grid<Tile>) -> Result<Self> {
+        let area = tiles.iter().filter(|(_, &t)| t != Tile::Void).count();
+        let size = ((area / 6) as f64).sqrt().round() as usize;
+        if size == 0 || 6 * size * size != area {
+            return Err(anyhow!("Board with {area} tiles is no cube net"));
+        }
+        let on_net = |(fx, fy): Pos| {
+            tiles
+                .get((fx * size, fy * size))
+                .is_some_and(|&t| t != Tile::Void)
+        };
+        let first = (0..tiles.width() / size)
+            .map(|fx| (fx, 0))
+            .find(|&face| on_net(face))
+            .ok_or_else(|| anyhow!("No face in the top row"))?;
+
+        let mut faces: HashMap<Pos, Face> = HashMap::new();
+        let mut queue = vec![(
+            first,
+            Face {
+                origin: (first.0 * size, first.1 * size),
+                normal: [0, 0, 1],
+                right: [1, 0, 0],
+                down: [0, 1, 0],
+            },
+        )];
+        while let Some(((fx, fy), face)) = queue.pop() {
+            if faces.contains_key(&(fx, fy)) {
+                continue;
+            }
+            let (n, r, d) = (face.normal, face.right, face.down);
+            let neighbors = [
+                (fx.checked_add(1), Some(fy), r, neg(n), d),
+                (Some(fx), fy.checked_add(1), d, r, neg(n)),
+                (fx.checked_sub(1), Some(fy), neg(r), n, d),
+                (Some(fx), fy.checked_sub(1), neg(d), r, n),
+            ];
+            for (nx, ny, normal, right, down) in neighbors {
+                let Some(next) = nx.zip(ny).filter(|&next| on_net(next)) else {
+                    continue;
+                };
+                let origin = (next.0 * size, next.1 * size);
+                queue.push((
+                    next,
+                    Face {
+                        origin,
+                        normal,
+                        right,
+                        down,
+                    },
+                ));
+            }
+            faces.insert((fx, fy), face);
+        }
+
+        let faces = faces.into_values().collect_vec();
+        if faces.iter().map(|f| f.normal).unique().count() != 6 || faces.len() != 6 {
+            return Err(anyhow!("The net does not fold into a cube"));
+        }
+        Ok(Self { size, faces })
+    }
+
+    fn face_of(&self, (x, y): Pos) -> &Face {
+        self.faces
+            .iter()
+            .find(|f| {
+                (f.origin.0..f.origin.0 + self.size).contains(&x)
+                    && (f.origin.1..f.origin.1 + self.size).contains(&y)
+            })
+            .expect("Position is on the cube")
+    }
+
+    /// Walk over the edge of the face at `pos` onto the adjacent face.
+    fn wrap(&self, pos: Pos, dir: usize) -> (Pos, usize) {
+        let s = self.size as i32;
+        let from = self.face_of(pos);
+        // Tile centers in 3D, using doubled coordinates so that they are integers
+        let (i, j) = (
+            (pos.0 - from.origin.0) as i32,
+            (pos.1 - from.origin.1) as i32,
+        );
+        let point = add(
+            scale(from.normal, s),
+            add(
+                scale(from.right, 2 * i + 1 - s),
+                scale(from.down, 2 * j + 1 - s),
+            ),
+        );
+
+        let heading = [from.right, from.down, neg(from.right), neg(from.down)][dir];
+        let to = self
+            .faces
+            .iter()
+            .find(|f| f.normal == heading)
+            .expect("Every direction has a face");
+        // Rotate over the edge: one half step out of the old face and one half step into the new one
+        let point = add(point, add(heading, neg(from.normal)));
+        let i = (dot(point, to.right) + s - 1) / 2;
+        let j = (dot(point, to.down) + s - 1) / 2;
+
+        let inward = neg(from.normal);
+        let dir = [to.right, to.down, neg(to.right), neg(to.down)]
+            .iter()
+            .position(|&v| v == inward)
+            .expect("The new face is adjacent");
+        ((to.origin.0 + i as usize, to.origin.1 + j as usize), dir)
+    }
+}
+
+fn input_generator(input: &str) -> Result<Board> {
+    input.parse()
+}
+
+fn part1(input: &Board) -> Result<usize> {
+    input.walk(|pos, dir| input.flat_wrap(pos, dir))
+}
+
+fn part2(input: &Board) -> Result<usize> {
+    let cube = Cube::fold(&input.tiles)?;
+    input.walk(|pos, dir| cube.wrap(pos, dir))
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day22>();
+
+struct Day22;
+
+impl Solution for Day22 {
+    type Input = Board;
+
+    const DAY: u32 = 22;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_parse() -> Result<()> {
+    let values = input_generator(&example_input(22, Part::One, 0)?)?;
+    assert_eq!((16, 12), (values.tiles.width(), values.tiles.height()));
+    assert_eq!(Tile::Void, values.tiles[(15, 0)]);
+    assert_eq!(Tile::Wall, values.tiles[(11, 0)]);
+    assert_eq!(13, values.path.len());
+    assert_eq!(Step::Forward(10), values.path[0]);
+    assert_eq!(Step::Forward(5), values.path[12]);
+    Ok(())
+}
+
+#[test]
+fn test_fold() -> Result<()> {
+    // The example net and the net of the actual puzzle inputs, without walls
+    for net in ["  #\n###\n  ##", " ##\n #\n##\n#"] {
+        let size = 3;
+        let map = net
+            .lines()
+            .flat_map(|line| {
+                let row = line.chars().map(|c| c.to_string().repeat(size)).join("");
+                std::iter::repeat_n(row.replace('#', "."), size)
+            })
+            .join("\n");
+        let board: Board = format!("{map}\n\n0").parse()?;
+        let cube = Cube::fold(&board.tiles)?;
+
+        // Walking straight ahead all the way around the cube leads back to the start
+        for (pos, _) in board.tiles.iter().filter(|(_, &t)| t == Tile::Open) {
+            for start_dir in 0..4 {
+                let (mut p, mut dir) = (pos, start_dir);
+                for _ in 0..4 * size {
+                    (p, dir) = board
+                        .next(p, dir)
+                        .map_or_else(|| cube.wrap(p, dir), |n| (n, dir));
+                }
+                assert_eq!((pos, start_dir), (p, dir), "{net:?} from {pos:?}");
+            }
+        }
+    }
+    Ok(())
+}
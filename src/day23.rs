@@ -0,0 +1,580 @@
+//! # Day 23: Unstable Diffusion
+//!
+//! ## --- Part One ---
+//!
+//! You enter a large crater of gray dirt where the grove is supposed to be.
+//! All around you, plants you imagine were expected to be full of fruit are instead withered and broken.
+//! A large group of Elves has formed in the middle of the grove.
+//!
+//! "...but this volcano has been dormant for months.
+//! Without ash, the fruit can't grow!"
+//!
+//! You look up to see a massive, snow-capped mountain towering above you.
+//!
+//! "It's not like there are other active volcanoes here; we've looked everywhere."
+//!
+//! "But our scanners show active magma flows; clearly it's going _somewhere_."
+//!
+//! They finally notice you at the edge of the grove, your pack almost overflowing from the random _star_ fruit you've been collecting.
+//! Behind you, elephants and monkeys explore the grove, looking concerned.
+//! Then, the Elves recognize the ash cloud slowly spreading above your recent detour.
+//!
+//! "Why do you--" "How is--" "Did you just--"
+//!
+//! Before any of them can form a complete question, another Elf speaks up: "Okay, new plan.
+//! We have almost enough fruit already, and ash from the plume should spread here eventually.
+//! If we quickly plant new seedlings now, we can still make it to the extraction point.
+//! Spread out!"
+//!
+//! The Elves each reach into their pack and pull out a tiny plant.
+//! The plants rely on important nutrients from the ash, so they can't be planted too close together.
+//!
+//! There isn't enough time to let the Elves figure out where to plant the seedlings themselves; you quickly scan the grove (your puzzle input) and note their positions.
+//!
+//! For example:
+//!
+//! ```text
+//! ....#..
+//! ..###.#
+//! #...#.#
+//! .#...##
+//! #.###..
+//! ##.#.##
+//! .#..#..
+//! ```
+//!
+//! The scan shows Elves `#` and empty ground `.`; outside your scan, more empty ground extends a long way in every direction.
+//! The scan is oriented so that _north is up_; orthogonal directions are written N (north), S (south), W (west), and E (east), while diagonal directions are written NE, NW, SE, SW.
+//!
+//! The Elves follow a time-consuming process to figure out where they should each go; you can speed up this process considerably.
+//! The process consists of some number of _rounds_ during which Elves alternate between considering where to move and actually moving.
+//!
+//! During the _first half_ of each round, each Elf considers the eight positions adjacent to themself.
+//! If no other Elves are in one of those eight positions, the Elf _does not do anything_ during this round.
+//! Otherwise, the Elf looks in each of four directions in the following order and _proposes_ moving one step in the _first valid direction_:
+//!
+//! - If there is no Elf in the N, NE, or NW adjacent positions, the Elf proposes moving _north_ one step.
+//! - If there is no Elf in the S, SE, or SW adjacent positions, the Elf proposes moving _south_ one step.
+//! - If there is no Elf in the W, NW, or SW adjacent positions, the Elf proposes moving _west_ one step.
+//! - If there is no Elf in the E, NE, or SE adjacent positions, the Elf proposes moving _east_ one step.
+//!
+//! After each Elf has had a chance to propose a move, the _second half_ of the round can begin.
+//! Simultaneously, each Elf moves to their proposed destination tile if they were the _only_ Elf to propose moving to that position.
+//! If two or more Elves propose moving to the same position, _none_ of those Elves move.
+//!
+//! Finally, at the end of the round, the _first direction_ the Elves considered is moved to the end of the list of directions.
+//! For example, during the second round, the Elves would try proposing a move to the south first, then west, then east, then north.
+//! On the third round, the Elves would first consider west, then east, then north, then south.
+//!
+//! As a smaller example, consider just these five Elves:
+//!
+//! ```text
+//! .....
+//! ..##.
+//! ..#..
+//! .....
+//! ..##.
+//! .....
+//! ```
+//!
+//! The northernmost two Elves and southernmost two Elves all propose moving north, while the middle Elf cannot move north and proposes moving south.
+//! The middle Elf proposes the same destination as the southwest Elf, so neither of them move, but the other three do:
+//!
+//! ```text
+//! ..##.
+//! .....
+//! ..#..
+//! ...#.
+//! ..#..
+//! .....
+//! ```
+//!
+//! Next, the northernmost two Elves and the southernmost Elf all propose moving south.
+//! Of the remaining middle two Elves, the west one cannot move south and proposes moving west, while the east one cannot move south _or_ west and proposes moving east.
+//! All five Elves succeed in moving to their proposed positions:
+//!
+//! ```text
+//! .....
+//! ..##.
+//! .#...
+//! ....#
+//! .....
+//! ..#..
+//! ```
+//!
+//! Finally, the southernmost two Elves choose not to move at all.
+//! Of the remaining three Elves, the west one proposes moving west, the east one proposes moving east, and the middle one proposes moving north; all three succeed in moving:
+//!
+//! ```text
+//! ..#..
+//! ....#
+//! #....
+//! ....#
+//! .....
+//! ..#..
+//! ```
+//!
+//! At this point, no Elves need to move, and so the process ends.
+//!
+//! The larger example above proceeds as follows:
+//!
+//! ```text
+//! == Initial State ==
+//! ..............
+//! ..............
+//! .......#......
+//! .....###.#....
+//! ...#...#.#....
+//! ....#...##....
+//! ...#.###......
+//! ...##.#.##....
+//! ....#..#......
+//! ..............
+//! ..............
+//! ..............
+//!
+//! == End of Round 1 ==
+//! ..............
+//! .......#......
+//! .....#...#....
+//! ...#..#.#.....
+//! .......#..#...
+//! ....#.#.##....
+//! ..#..#.#......
+//! ..#.#.#.##....
+//! ..............
+//! ....#..#......
+//! ..............
+//! ..............
+//!
+//! == End of Round 2 ==
+//! ..............
+//! .......#......
+//! ....#.....#...
+//! ...#..#.#.....
+//! .......#...#..
+//! ...#..#.#.....
+//! .#...#.#.#....
+//! ..............
+//! ..#.#.#.##....
+//! ....#..#......
+//! ..............
+//! ..............
+//!
+//! == End of Round 3 ==
+//! ..............
+//! .......#......
+//! .....#....#...
+//! ..#..#...#....
+//! .......#...#..
+//! ...#..#.#.....
+//! .#..#.....#...
+//! .......##.....
+//! ..##.#....#...
+//! ...#..........
+//! .......#......
+//! ..............
+//!
+//! == End of Round 4 ==
+//! ..............
+//! .......#......
+//! ......#....#..
+//! ..#...##......
+//! ...#.....#.#..
+//! .........#....
+//! .#...###..#...
+//! ..#......#....
+//! ....##....#...
+//! ....#.........
+//! .......#......
+//! ..............
+//!
+//! == End of Round 5 ==
+//! .......#......
+//! ..............
+//! ..#..#.....#..
+//! .........#....
+//! ......##...#..
+//! .#.#.####.....
+//! ...........#..
+//! ....##..#.....
+//! ..#...........
+//! ..........#...
+//! ....#..#......
+//! ..............
+//!
+//! == End of Round 10 ==
+//! .......#......
+//! ...........#..
+//! ..#.#..#......
+//! ......#.......
+//! ...#.....#..#.
+//! .#......##....
+//! .....##.......
+//! ..#........#..
+//! ....#.#..#....
+//! ..............
+//! ....#..#..#...
+//! ..............
+//! ```
+//!
+//! To make sure they're on the right track, the Elves like to check after round 10 that they're making good progress toward covering enough ground.
+//! To do this, count the number of empty ground tiles contained by the smallest rectangle that contains every Elf.
+//! (The edges of the rectangle should be aligned to the N/S/E/W directions; the Elves do not have the patience to calculate arbitrary rectangles.)
+//! In the above example, that rectangle is:
+//!
+//! ```text
+//! ......#.....
+//! ..........#.
+//! .#.#..#.....
+//! .....#......
+//! ..#.....#..#
+//! #......##...
+//! ....##......
+//! .#........#.
+//! ...#.#..#...
+//! ............
+//! ...#..#..#..
+//! ```
+//!
+//! In this region, the number of empty ground tiles is _`110`_.
+//!
+//! Simulate the Elves' process and find the smallest rectangle that contains the Elves after 10 rounds.
+//! _How many empty ground tiles does that rectangle contain?_
+//!
+//! ## --- Part Two ---
+//!
+//! It seems you're on the right track.
+//! Finish simulating the process and figure out where the Elves need to go.
+//! How many rounds did you save them?
+//!
+//! In the example above, the first round where no Elf moved was round _`20`_:
+//!
+//! ```text
+//! .......#......
+//! ....#......#..
+//! ..#.....#.....
+//! ......#.......
+//! ...#....#.#..#
+//! #.............
+//! ....#.....#...
+//! ..#.....#.....
+//! ....#.#....#..
+//! .........#....
+//! ....#......#..
+//! .......#......
+//! ```
+//!
+//! Figure out where the Elves need to go.
+//! _What is the number of the first round where no Elf moves?_
+
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+
+type Pos = (i64, i64);
+
+/// Extra space added around the grid whenever it has to grow
+const GROW_MARGIN: i64 = 16;
+
+/// The three positions checked for each proposed direction, with the move itself in the middle.
+const DIRECTIONS: [[Pos; 3]; 4] = [
+    [(-1, -1), (0, -1), (1, -1)],
+    [(-1, 1), (0, 1), (1, 1)],
+    [(-1, -1), (-1, 0), (-1, 1)],
+    [(1, -1), (1, 0), (1, 1)],
+];
+
+/// A set of positions on an unbounded plane, stored as one bit per tile.
+///
+/// The grid grows whenever a position outside of it is inserted.
+#[derive(Debug, Clone)]
+struct BitGrid {
+    /// Position of the top left tile
+    min: Pos,
+    /// Number of 64 bit words per row
+    words: usize,
+    height: usize,
+    bits: Vec<u64>,
+}
+
+impl BitGrid {
+    fn new() -> Self {
+        Self {
+            min: (0, 0),
+            words: 0,
+            height: 0,
+            bits: Vec::new(),
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.words * 64
+    }
+
+    /// Word index and bit mask of `pos`, if it is inside the grid.
+    fn index(&self, (x, y): Pos) -> Option<(usize, u64)> {
+        let x = usize::try_from(x - self.min.0).ok()?;
+        let y = usize::try_from(y - self.min.1).ok()?;
+        (x < self.width() && y < self.height).then(|| (y * self.words + x / 64, 1 << (x % 64)))
+    }
+
+    fn contains(&self, pos: Pos) -> bool {
+        self.index(pos)
+            .is_some_and(|(word, mask)| self.bits[word] & mask != 0)
+    }
+
+    /// Add `pos` and return whether it was not in the set yet.
+    fn insert(&mut self, pos: Pos) -> bool {
+        let (word, mask) = match self.index(pos) {
+            Some(index) => index,
+            None => {
+                self.grow_to(pos);
+                self.index(pos).expect("Grown to include the position")
+            }
+        };
+        let added = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+        added
+    }
+
+    fn remove(&mut self, pos: Pos) {
+        if let Some((word, mask)) = self.index(pos) {
+            self.bits[word] &= !mask;
+        }
+    }
+
+    /// Reallocate the grid to include `pos`, with some margin so that this doesn't happen on every step.
+    fn grow_to(&mut self, (x, y): Pos) {
+        let old = std::mem::replace(self, Self::new());
+        let (min_x, min_y) = if old.height == 0 {
+            (x, y)
+        } else {
+            (old.min.0.min(x), old.min.1.min(y))
+        };
+        let (max_x, max_y) = if old.height == 0 {
+            (x, y)
+        } else {
+            let max = (
+                old.min.0 + old.width() as i64 - 1,
+                old.min.1 + old.height as i64 - 1,
+            );
+            (max.0.max(x), max.1.max(y))
+        };
+
+        self.min = (min_x - GROW_MARGIN, min_y - GROW_MARGIN);
+        self.words = ((max_x - min_x + 1 + 2 * GROW_MARGIN) as usize).div_ceil(64);
+        self.height = (max_y - min_y + 1 + 2 * GROW_MARGIN) as usize;
+        self.bits = vec![0; self.words * self.height];
+        for pos in old.iter() {
+            self.insert(pos);
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.bits.iter().enumerate().flat_map(move |(word, &bits)| {
+            let (row, col) = (word / self.words, (word % self.words) * 64);
+            (0..64)
+                .filter(move |bit| bits & (1 << bit) != 0)
+                .map(move |bit| (self.min.0 + (col + bit) as i64, self.min.1 + row as i64))
+        })
+    }
+
+    /// Smallest and largest position of the bounding box of all positions in the set.
+    fn bounds(&self) -> Option<(Pos, Pos)> {
+        let (min_x, max_x) = self.iter().map(|(x, _)| x).minmax().into_option()?;
+        let (min_y, max_y) = self.iter().map(|(_, y)| y).minmax().into_option()?;
+        Some(((min_x, min_y), (max_x, max_y)))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Grove {
+    elves: BitGrid,
+    rounds: usize,
+}
+
+impl FromStr for Grove {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elves = BitGrid::new();
+        for (y, line) in s.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        elves.insert((x as i64, y as i64));
+                    }
+                    '.' => {}
+                    _ => return Err(anyhow!("Invalid tile: {}", c)),
+                }
+            }
+        }
+        Ok(Self { elves, rounds: 0 })
+    }
+}
+
+impl Grove {
+    /// Run one round and return whether any elf moved.
+    fn round(&mut self) -> bool {
+        let occupied = |(x, y): Pos, (dx, dy): Pos| self.elves.contains((x + dx, y + dy));
+
+        let mut proposals = Vec::new();
+        let mut proposed = BitGrid::new();
+        let mut contested = BitGrid::new();
+        for elf in self.elves.iter() {
+            let free = (0..4)
+                .map(|i| DIRECTIONS[(self.rounds + i) % 4])
+                .filter(|checks| !checks.iter().any(|&d| occupied(elf, d)))
+                .collect_vec();
+            // All four directions are free exactly when there are no neighbors, so the elf stays
+            let Some(&[_, (dx, dy), _]) = free.first().filter(|_| free.len() < 4) else {
+                continue;
+            };
+            let target = (elf.0 + dx, elf.1 + dy);
+            if !proposed.insert(target) {
+                contested.insert(target);
+            }
+            proposals.push((elf, target));
+        }
+
+        let mut moved = false;
+        for (elf, target) in proposals {
+            if !contested.contains(target) {
+                self.elves.remove(elf);
+                self.elves.insert(target);
+                moved = true;
+            }
+        }
+        self.rounds += 1;
+        moved
+    }
+
+    fn empty_ground(&self) -> usize {
+        let Some(((min_x, min_y), (max_x, max_y))) = self.elves.bounds() else {
+            return 0;
+        };
+        let area = ((max_x - min_x + 1) * (max_y - min_y + 1)) as usize;
+        area - self.elves.iter().count()
+    }
+
+    /// Render the smallest rectangle containing all elves.
+    #[cfg(test)]
+    fn display(&self) -> String {
+        let Some(((min_x, min_y), (max_x, max_y))) = self.elves.bounds() else {
+            return String::new();
+        };
+        let mut s = String::new();
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                s.push(if self.elves.contains((x, y)) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+fn input_generator(input: &str) -> Result<Grove> {
+    input.parse()
+}
+
+fn part1(input: &Grove) -> usize {
+    let mut grove = input.clone();
+    for _ in 0..10 {
+        grove.round();
+    }
+    grove.empty_ground()
+}
+
+fn part2(input: &Grove) -> usize {
+    let mut grove = input.clone();
+    while grove.round() {}
+    grove.rounds
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day23>();
+
+struct Day23;
+
+impl Solution for Day23 {
+    type Input = Grove;
+
+    const DAY: u32 = 23;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_bit_grid() {
+    let mut grid = BitGrid::new();
+    assert!(grid.insert((0, 0)));
+    assert!(!grid.insert((0, 0)));
+    // Grows in every direction, also across word boundaries
+    assert!(grid.insert((-100, 3)));
+    assert!(grid.insert((150, -70)));
+    assert!(grid.contains((0, 0)) && grid.contains((-100, 3)) && grid.contains((150, -70)));
+    assert!(!grid.contains((1, 0)) && !grid.contains((1000, 1000)));
+    grid.remove((0, 0));
+    assert_eq!(vec![(150, -70), (-100, 3)], grid.iter().collect_vec());
+    assert_eq!(Some(((-100, -70), (150, 3))), grid.bounds());
+}
+
+#[test]
+fn test_display() -> Result<()> {
+    let mut grove = input_generator(&example_input(23, Part::One, 1)?)?;
+    for index in 2..=4 {
+        assert!(grove.round());
+        let expected = input_generator(&example_input(23, Part::One, index)?)?;
+        assert_eq!(expected.display(), grove.display());
+    }
+    assert!(!grove.round());
+
+    // The walkthrough draws every listed round within the same window
+    let walkthrough = example_input(23, Part::One, 5)?;
+    let mut frames = walkthrough.split("== ").filter(|frame| !frame.is_empty());
+    let (_, initial) = frames.next().unwrap().split_once('\n').unwrap();
+    let mut grove = input_generator(initial)?;
+    for frame in frames {
+        let (title, map) = frame.split_once('\n').unwrap();
+        let round: usize = title
+            .trim_start_matches("End of Round ")
+            .trim_end_matches(" ==")
+            .parse()?;
+        while grove.rounds < round {
+            assert!(grove.round());
+        }
+        let expected = input_generator(map)?;
+        assert_eq!(
+            expected.elves.iter().sorted().collect_vec(),
+            grove.elves.iter().sorted().collect_vec()
+        );
+    }
+    assert_eq!(10, grove.rounds);
+    assert_eq!(example_input(23, Part::One, 6)? + "\n", grove.display());
+
+    while grove.round() {}
+    assert_eq!(20, grove.rounds);
+    let expected = input_generator(&example_input(23, Part::Two, 0)?)?;
+    assert_eq!(
+        expected.elves.iter().sorted().collect_vec(),
+        grove.elves.iter().sorted().collect_vec()
+    );
+    Ok(())
+}
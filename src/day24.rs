@@ -0,0 +1,555 @@
+//! # Day 24: Blizzard Basin
+//!
+//! ## --- Part One ---
+//!
+//! With everything replanted for next year (and with elephants and monkeys to tend the grove), you and the Elves leave for the extraction point.
+//!
+//! Partway up the mountain that shields the grove is a flat, open area that serves as the extraction point.
+//! It's a bit of a climb, but nothing the expedition can't handle.
+//!
+//! At least, that would normally be true; now that the mountain is covered in snow, things have become more difficult than the Elves are used to.
+//!
+//! As the expedition reaches a valley that must be traversed to reach the extraction site, you find that strong, turbulent winds are pushing small _blizzards_ of snow and sharp ice around the valley.
+//! It's a good thing everyone packed warm clothes!
+//! To make it across safely, you'll need to find a way to avoid them.
+//!
+//! Fortunately, it's easy to see all of this from the entrance to the valley, so you make a map of the valley and the blizzards (your puzzle input).
+//! For example:
+//!
+//! ```text
+//! #.#####
+//! #.....#
+//! #>....#
+//! #.....#
+//! #...v.#
+//! #.....#
+//! #####.#
+//! ```
+//!
+//! The walls of the valley are drawn as `#`; everything else is ground.
+//! Clear ground - where there is currently no blizzard - is drawn as `.`.
+//! Otherwise, blizzards are drawn with an arrow indicating their direction of motion: up (`^`), down (`v`), left (`<`), or right (`>`).
+//!
+//! The above map includes two blizzards, one moving right (`>`) and one moving down (`v`).
+//! In one minute, each blizzard moves one position in the direction it is pointing:
+//!
+//! ```text
+//! #.#####
+//! #.....#
+//! #.>...#
+//! #.....#
+//! #.....#
+//! #...v.#
+//! #####.#
+//! ```
+//!
+//! Due to conservation of blizzard energy, as a blizzard reaches the wall of the valley, a new blizzard forms on the opposite side of the valley moving in the same direction.
+//! After another minute, the bottom `v` blizzard has been replaced with a new `v` blizzard at the top of the valley instead:
+//!
+//! ```text
+//! #.#####
+//! #...v.#
+//! #..>..#
+//! #.....#
+//! #.....#
+//! #.....#
+//! #####.#
+//! ```
+//!
+//! Because blizzards are made of tiny snowflakes, they pass right through each other.
+//! After another minute, both blizzards temporarily occupy the same position, marked `2`:
+//!
+//! ```text
+//! #.#####
+//! #.....#
+//! #...2.#
+//! #.....#
+//! #.....#
+//! #.....#
+//! #####.#
+//! ```
+//!
+//! After another minute, the situation resolves itself, giving each blizzard back its personal space:
+//!
+//! ```text
+//! #.#####
+//! #.....#
+//! #....>#
+//! #...v.#
+//! #.....#
+//! #.....#
+//! #####.#
+//! ```
+//!
+//! Finally, after yet another minute, the rightmost `>` blizzard has been replaced with a new `>` blizzard on the left side of the valley:
+//!
+//! ```text
+//! #.#####
+//! #.....#
+//! #>....#
+//! #.....#
+//! #...v.#
+//! #.....#
+//! #####.#
+//! ```
+//!
+//! Here is a more complex example:
+//!
+//! ```text
+//! #.######
+//! #>>.<^<#
+//! #.<..<<#
+//! #>v.><>#
+//! #<^v^^>#
+//! ######.#
+//! ```
+//!
+//! Your expedition begins in the only non-wall position in the top row and needs to reach the only non-wall position in the bottom row.
+//! On each minute, you can _move_ up, down, left, or right, or you can _wait_ in place.
+//! You and the blizzards act _simultaneously_, and you cannot share a position with a blizzard.
+//!
+//! In the above example, the fastest way to reach your goal requires _`18`_ steps.
+//! Drawing the position of the expedition as `E`, one way to achieve this is:
+//!
+//! ```text
+//! Initial state:
+//! #E######
+//! #>>.<^<#
+//! #.<..<<#
+//! #>v.><>#
+//! #<^v^^>#
+//! ######.#
+//!
+//! Minute 1, move down:
+//! #.######
+//! #E>3.<.#
+//! #<..<<.#
+//! #>2.22.#
+//! #>v..^<#
+//! ######.#
+//!
+//! Minute 2, move down:
+//! #.######
+//! #.2>2..#
+//! #E^22^<#
+//! #.>2.^>#
+//! #.>..<.#
+//! ######.#
+//!
+//! Minute 3, wait:
+//! #.######
+//! #<^<22.#
+//! #E2<.2.#
+//! #><2>..#
+//! #..><..#
+//! ######.#
+//!
+//! Minute 4, move up:
+//! #.######
+//! #E<..22#
+//! #<<.<..#
+//! #<2.>>.#
+//! #.^22^.#
+//! ######.#
+//!
+//! Minute 5, move right:
+//! #.######
+//! #2Ev.<>#
+//! #<.<..<#
+//! #.^>^22#
+//! #.2..2.#
+//! ######.#
+//!
+//! Minute 6, move right:
+//! #.######
+//! #>2E<.<#
+//! #.2v^2<#
+//! #>..>2>#
+//! #<....>#
+//! ######.#
+//!
+//! Minute 7, move down:
+//! #.######
+//! #.22^2.#
+//! #<vE<2.#
+//! #>>v<>.#
+//! #>....<#
+//! ######.#
+//!
+//! Minute 8, move left:
+//! #.######
+//! #.<>2^.#
+//! #.E<<.<#
+//! #.22..>#
+//! #.2v^2.#
+//! ######.#
+//!
+//! Minute 9, move up:
+//! #.######
+//! #<E2>>.#
+//! #.<<.<.#
+//! #>2>2^.#
+//! #.v><^.#
+//! ######.#
+//!
+//! Minute 10, move right:
+//! #.######
+//! #.2E.>2#
+//! #<2v2^.#
+//! #<>.>2.#
+//! #..<>..#
+//! ######.#
+//!
+//! Minute 11, wait:
+//! #.######
+//! #2^E^2>#
+//! #<v<.^<#
+//! #..2.>2#
+//! #.<..>.#
+//! ######.#
+//!
+//! Minute 12, move down:
+//! #.######
+//! #>>.<^<#
+//! #.<E.<<#
+//! #>v.><>#
+//! #<^v^^>#
+//! ######.#
+//!
+//! Minute 13, move down:
+//! #.######
+//! #.>3.<.#
+//! #<..<<.#
+//! #>2E22.#
+//! #>v..^<#
+//! ######.#
+//!
+//! Minute 14, move right:
+//! #.######
+//! #.2>2..#
+//! #.^22^<#
+//! #.>2E^>#
+//! #.>..<.#
+//! ######.#
+//!
+//! Minute 15, move right:
+//! #.######
+//! #<^<22.#
+//! #.2<.2.#
+//! #><2>E.#
+//! #..><..#
+//! ######.#
+//!
+//! Minute 16, move right:
+//! #.######
+//! #.<..22#
+//! #<<.<..#
+//! #<2.>>E#
+//! #.^22^.#
+//! ######.#
+//!
+//! Minute 17, move down:
+//! #.######
+//! #2.v.<>#
+//! #<.<..<#
+//! #.^>^22#
+//! #.2..2E#
+//! ######.#
+//!
+//! Minute 18, move down:
+//! #.######
+//! #>2.<.<#
+//! #.2v^2<#
+//! #>..>2>#
+//! #<....>#
+//! ######E#
+//! ```
+//!
+//! _What is the fewest number of minutes required to avoid the blizzards and reach the goal?_
+//!
+//! ## --- Part Two ---
+//!
+//! As the expedition reaches the far side of the valley, one of the Elves looks especially dismayed:
+//!
+//! He _forgot his snacks_ at the entrance to the valley!
+//!
+//! Since you're so good at dodging blizzards, the Elves humbly request that you go back for his snacks.
+//! From the same initial conditions, how quickly can you make it from the start to the goal, then back to the start, then back to the goal?
+//!
+//! In the above example, the first trip to the goal takes `18` minutes, the trip back to the start takes `23` minutes, and the trip back to the goal again takes `13` minutes, for a total time of _`54`_ minutes.
+//!
+//! _What is the fewest number of minutes required to reach the goal, go back to the start, then reach the goal again?_
+
+use crate::grid::Grid;
+use crate::prelude::*;
+use crate::solution::{Entry, IntoAnswer, Solution};
+#[cfg(test)]
+use crate::{examples::example_input, solution::Part};
+use std::collections::VecDeque;
+
+type Pos = (usize, usize);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir {
+    fn symbol(self) -> char {
+        match self {
+            Dir::Up => '^',
+            Dir::Down => 'v',
+            Dir::Left => '<',
+            Dir::Right => '>',
+        }
+    }
+}
+
+/// The valley with all blizzards, in map coordinates including the walls.
+#[derive(Debug, Clone)]
+struct Valley {
+    /// Size of the inside of the valley, without the walls
+    width: usize,
+    height: usize,
+    start: Pos,
+    end: Pos,
+    blizzards: Vec<(Pos, Dir)>,
+    /// Left and right blizzards of every inner row, at `(x, t % width)` if they cover column `x` after `t` minutes.
+    /// Horizontal blizzards never leave their row, so they are back where they started after `width` minutes.
+    rows: Vec<Grid<bool>>,
+    /// Up and down blizzards of every inner column, at `(y, t % height)` if they cover row `y` after `t` minutes
+    columns: Vec<Grid<bool>>,
+}
+
+impl FromStr for Valley {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().collect_vec();
+        if lines.len() < 3 || lines[0].len() < 3 {
+            return Err(anyhow!("Valley is too small"));
+        }
+        let (width, height) = (lines[0].len() - 2, lines.len() - 2);
+        let gap = |line: &str| {
+            line.find('.')
+                .ok_or_else(|| anyhow!("No gap in the wall: {}", line))
+        };
+        let start = (gap(lines[0])?, 0);
+        let end = (gap(lines[height + 1])?, height + 1);
+
+        let mut blizzards = Vec::new();
+        for (y, line) in lines[1..=height].iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let dir = match c {
+                    '#' | '.' => continue,
+                    '^' => Dir::Up,
+                    'v' => Dir::Down,
+                    '<' => Dir::Left,
+                    '>' => Dir::Right,
+                    _ => return Err(anyhow!("Invalid tile: {}", c)),
+                };
+                blizzards.push(((x, y + 1), dir));
+            }
+        }
+
+        let mut rows = vec![Grid::new(width, width, false); height];
+        let mut columns = vec![Grid::new(height, height, false); width];
+        for &((x, y), dir) in &blizzards {
+            let (x, y) = (x - 1, y - 1);
+            match dir {
+                Dir::Right => (0..width).for_each(|t| rows[y][((x + t) % width, t)] = true),
+                Dir::Left => (0..width).for_each(|t| rows[y][((x + width - t) % width, t)] = true),
+                Dir::Down => (0..height).for_each(|t| columns[x][((y + t) % height, t)] = true),
+                Dir::Up => (0..height).for_each(|t| columns[x][((y + height - t) % height, t)] = true),
+            }
+        }
+        Ok(Self {
+            width,
+            height,
+            start,
+            end,
+            blizzards,
+            rows,
+            columns,
+        })
+    }
+}
+
+impl Valley {
+    /// Number of minutes after which all blizzards are back at their initial positions.
+    fn period(&self) -> usize {
+        self.width * self.height / gcd(self.width, self.height)
+    }
+
+    /// Position and direction of every blizzard after `t` minutes.
+    fn blizzards_at(&self, t: usize) -> impl Iterator<Item = (Pos, Dir)> + '_ {
+        let (w, h) = (self.width, self.height);
+        self.blizzards.iter().map(move |&((x, y), dir)| {
+            let (x, y) = (x - 1, y - 1);
+            let pos = match dir {
+                Dir::Right => ((x + t) % w, y),
+                Dir::Left => ((x + w - t % w) % w, y),
+                Dir::Down => (x, (y + t) % h),
+                Dir::Up => (x, (y + h - t % h) % h),
+            };
+            ((pos.0 + 1, pos.1 + 1), dir)
+        })
+    }
+
+    /// Whether the expedition can stand on `pos` after `t` minutes.
+    fn is_free(&self, (x, y): Pos, t: usize) -> bool {
+        if (x, y) == self.start || (x, y) == self.end {
+            return true;
+        }
+        if !(1..=self.width).contains(&x) || !(1..=self.height).contains(&y) {
+            return false;
+        }
+        let (x, y) = (x - 1, y - 1);
+        !self.rows[y][(x, t % self.width)] && !self.columns[x][(y, t % self.height)]
+    }
+
+    /// Fewest minutes to walk from `from` to `to` when setting out after `start_time` minutes.
+    fn crossing(&self, from: Pos, to: Pos, start_time: usize) -> Result<usize> {
+        let period = self.period();
+        let mut seen = HashSet::from([(from, start_time % period)]);
+        let mut queue = VecDeque::from([(from, start_time)]);
+
+        while let Some(((x, y), t)) = queue.pop_front() {
+            if (x, y) == to {
+                return Ok(t - start_time);
+            }
+            let moves = [
+                Some((x, y)),
+                Some((x + 1, y)),
+                x.checked_sub(1).map(|x| (x, y)),
+                Some((x, y + 1)),
+                y.checked_sub(1).map(|y| (x, y)),
+            ];
+            for next in moves.into_iter().flatten() {
+                if self.is_free(next, t + 1) && seen.insert((next, (t + 1) % period)) {
+                    queue.push_back((next, t + 1));
+                }
+            }
+        }
+        Err(anyhow!("No way from {from:?} to {to:?}"))
+    }
+
+    /// Render the valley after `t` minutes like the puzzle description, with the expedition as `E`.
+    #[allow(dead_code)]
+    fn display(&self, t: usize, expedition: Option<Pos>) -> String {
+        let mut map = Grid::new(self.width + 2, self.height + 2, '#');
+        for y in 1..=self.height {
+            for x in 1..=self.width {
+                map[(x, y)] = '.';
+            }
+        }
+        map[self.start] = '.';
+        map[self.end] = '.';
+        for (pos, dir) in self.blizzards_at(t) {
+            map[pos] = match map[pos] {
+                '.' => dir.symbol(),
+                '^' | 'v' | '<' | '>' => '2',
+                n => char::from_digit(n.to_digit(10).unwrap_or(0) + 1, 10).unwrap_or('+'),
+            };
+        }
+        if let Some(pos) = expedition {
+            map[pos] = 'E';
+        }
+        map.display(|&c| c)
+    }
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn input_generator(input: &str) -> Result<Valley> {
+    input.parse()
+}
+
+fn part1(input: &Valley) -> Result<usize> {
+    input.crossing(input.start, input.end, 0)
+}
+
+fn part2(input: &Valley) -> Result<usize> {
+    let there = input.crossing(input.start, input.end, 0)?;
+    let back = input.crossing(input.end, input.start, there)?;
+    let there_again = input.crossing(input.start, input.end, there + back)?;
+    Ok(there + back + there_again)
+}
+
+/// Registry entry of this day
+pub(crate) const ENTRY: Entry = Entry::new::<Day24>();
+
+struct Day24;
+
+impl Solution for Day24 {
+    type Input = Valley;
+
+    const DAY: u32 = 24;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String> {
+        part1(input).into_answer()
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input).into_answer()
+    }
+}
+
+#[test]
+fn test_display() -> Result<()> {
+    let values = input_generator(&example_input(24, Part::One, 0)?)?;
+    for t in 0..=5 {
+        let expected = example_input(24, Part::One, t)? + "\n";
+        assert_eq!(expected, values.display(t, None), "minute {t}");
+    }
+    assert_eq!(
+        values.display(0, None),
+        values.display(values.period(), None)
+    );
+
+    // Each step of the walkthrough, with the expedition wherever it is drawn
+    let values = input_generator(&example_input(24, Part::One, 6)?)?;
+    let walkthrough = example_input(24, Part::One, 7)?;
+    let mut expedition = values.start;
+    for (t, step) in walkthrough.split("\n\n").enumerate() {
+        let (_, map) = step
+            .split_once('\n')
+            .ok_or_else(|| anyhow!("No map in {step:?}"))?;
+        let map: Grid<char> = Grid::parse(map, Ok)?;
+        let pos = map
+            .position(|&c| c == 'E')
+            .ok_or_else(|| anyhow!("No expedition in {step:?}"))?;
+        assert!(values.is_free(pos, t), "minute {t}");
+        assert!(expedition.0.abs_diff(pos.0) + expedition.1.abs_diff(pos.1) <= 1);
+        assert_eq!(map.display(|&c| c), values.display(t, Some(pos)), "minute {t}");
+        expedition = pos;
+    }
+    assert_eq!(values.end, expedition);
+    Ok(())
+}
+
+#[test]
+fn test_period() -> Result<()> {
+    let values = input_generator(&example_input(24, Part::One, 6)?)?;
+    assert_eq!(12, values.period());
+    Ok(())
+}
+
+#[test]
+fn test_crossing() -> Result<()> {
+    let values = input_generator(&example_input(24, Part::One, 6)?)?;
+    assert_eq!(23, values.crossing(values.end, values.start, 18)?);
+    Ok(())
+}
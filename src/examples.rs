@@ -0,0 +1,223 @@
+//! Tests of every day against the example in its puzzle description.
+//!
+//! The module docs of each day hold the puzzle text, with the example input in a ```` ```text ```` block and
+//! the answers to it emphasized like _`42`_.
+//! The example input is the first block of part one, unless [`Example`] says otherwise,
+//! and the answer of a part is the last emphasized value in its description.
+//! Short examples listed like ``* `input`: ... _`42`_`` are checked as well where a part has them,
+//! the example input is then only checked if an answer is emphasized before the list.
+//! The examples are parsed with [`Solution::parse_example`](crate::solution::Solution::parse_example),
+//! so days whose example asks a smaller question than the real puzzle are checked too.
+//!
+//! The tests of the days take their example inputs and drawings from the docs too, using [`example_input`].
+
+use crate::prelude::*;
+use crate::solution::{find, Part};
+
+/// Where to find the example in the docs of a day, for days which do not follow the usual layout.
+#[derive(Debug, Copy, Clone)]
+struct Example {
+    /// Index of the block in part one holding the example input
+    input: usize,
+    /// Index of the block in part two, if that part has an example input of its own
+    part2_input: Option<usize>,
+    /// Parts whose emphasized answer does not belong to the example input
+    skip: &'static [Part],
+}
+
+impl Example {
+    const DEFAULT: Self = Self {
+        input: 0,
+        part2_input: None,
+        skip: &[],
+    };
+}
+
+/// The description of one part in the module docs.
+#[derive(Debug, Default)]
+struct Section {
+    blocks: Vec<String>,
+    answers: Vec<String>,
+    /// Inputs given inline in a list, with their answers
+    inline: Vec<(String, String)>,
+}
+
+/// Split the module docs of a day's source into the sections of the parts.
+fn sections(source: &str) -> Vec<Section> {
+    let mut sections = vec![Section::default()];
+    let mut block: Option<Vec<&str>> = None;
+    for line in source.lines().map_while(|line| line.strip_prefix("//!")) {
+        let line = line.strip_prefix(' ').unwrap_or(line);
+        let section = sections.last_mut().unwrap();
+        match &mut block {
+            // Some closing fences are indented by a space
+            Some(lines) if line.trim() == "```" => {
+                section.blocks.push(lines.join("\n"));
+                block = None;
+            }
+            Some(lines) => lines.push(line),
+            None if line == "```text" => block = Some(Vec::new()),
+            None if line.starts_with("## --- Part Two") => sections.push(Section::default()),
+            None => {
+                let before = section.answers.len();
+                let mut rest = line;
+                while let Some((_, emphasized)) = rest.split_once("_`") {
+                    let Some((answer, after)) = emphasized.split_once("`_") else {
+                        break;
+                    };
+                    section.answers.push(answer.to_string());
+                    rest = after;
+                }
+                let input = line
+                    .strip_prefix("* `")
+                    .and_then(|item| item.split_once("`:"));
+                if let (Some((input, _)), Some(answer)) = (input, section.answers.get(before)) {
+                    section.inline.push((input.to_string(), answer.clone()));
+                }
+            }
+        }
+    }
+    sections
+}
+
+/// Block `index` of the description of `part` in the module docs of a day's source.
+fn block(sections: &[Section], part: Part, index: usize) -> Result<&str> {
+    sections
+        .get(part.number() as usize - 1)
+        .and_then(|s| s.blocks.get(index))
+        .map(String::as_str)
+        .ok_or_else(|| anyhow!("There is no example block {index} in part {part}"))
+}
+
+/// The source of a day, whose module docs hold its examples.
+fn source(day: u32) -> Result<&'static str> {
+    let name = format!("day{day:02}");
+    SOURCES
+        .iter()
+        .find(|(file, _)| *file == name)
+        .map(|(_, source)| *source)
+        .ok_or_else(|| anyhow!("There is no source of day {day}"))
+}
+
+/// Block `index` of the description of `part` in the module docs of a day,
+/// for the tests of the days to use the examples without copying them.
+pub(crate) fn example_input(day: u32, part: Part, index: usize) -> Result<String> {
+    block(&sections(source(day)?), part, index)
+        .map(str::to_string)
+        .map_err(|err| anyhow!("Day {day}: {err}"))
+}
+
+/// Run all solvers of a day on the example in its docs, and compare them to the emphasized answers.
+fn check(day: u32, example: Example) -> Result<()> {
+    let entry = find(day).ok_or_else(|| anyhow!("There is no solution for day {day}"))?;
+    let sections = sections(source(day)?);
+    let run = |input: &str, solver| {
+        entry
+            .run_example(input, solver)
+            .answer
+            .map_err(|err| anyhow!("Day {day} {solver:?}: {err:#}"))
+    };
+
+    let mut checked = 0;
+    for solver in entry.solvers() {
+        if example.skip.contains(&solver.part) {
+            continue;
+        }
+        let section = sections
+            .get(solver.part.number() as usize - 1)
+            .ok_or_else(|| anyhow!("Day {day} has no description of part {}", solver.part))?;
+        // The answers of the inputs listed inline come last, one before them belongs to the example input
+        let answers = &section.answers[..section.answers.len() - section.inline.len()];
+        if !section.inline.is_empty() {
+            for (input, expected) in &section.inline {
+                let answer = run(input, solver)?;
+                assert_eq!(expected, &answer, "Day {day} {solver:?} on {input}");
+            }
+            checked += 1;
+            if answers.is_empty() {
+                continue;
+            }
+        }
+
+        let input = match (solver.part, example.part2_input) {
+            (Part::Two, Some(index)) => block(&sections, Part::Two, index)?,
+            _ => block(&sections, Part::One, example.input)?,
+        };
+        let expected = answers
+            .last()
+            .ok_or_else(|| anyhow!("Day {day} part {} has no emphasized answer", solver.part))?;
+        let answer = run(input, solver)?;
+        assert_eq!(expected, &answer, "Day {day} {solver:?}");
+        checked += 1;
+    }
+    assert!(checked > 0, "Day {day} has no example to check");
+    Ok(())
+}
+
+macro_rules! example_tests {
+    ($($day:ident => $example:expr,)*) => {
+        /// File name and source of every day
+        static SOURCES: &[(&str, &str)] = &[
+            $((stringify!($day), include_str!(concat!(stringify!($day), ".rs"))),)*
+        ];
+
+        $(
+            #[test]
+            fn $day() -> Result<()> {
+                check(stringify!($day).trim_start_matches("day").parse()?, $example)
+            }
+        )*
+    };
+}
+
+example_tests! {
+    day01 => Example::DEFAULT,
+    day02 => Example::DEFAULT,
+    day03 => Example::DEFAULT,
+    day04 => Example::DEFAULT,
+    day05 => Example::DEFAULT,
+    // Checks the datastream buffers listed inline
+    day06 => Example::DEFAULT,
+    // The first block is a command that is not part of the example
+    day07 => Example { input: 1, ..Example::DEFAULT },
+    day08 => Example::DEFAULT,
+    // The motions come after the diagrams of the rules, and part two has a larger example
+    day09 => Example { input: 3, part2_input: Some(1), ..Example::DEFAULT },
+    // Part two draws letters on the screen, which are not emphasized
+    day10 => Example { input: 1, skip: &[Part::Two], ..Example::DEFAULT },
+    day11 => Example::DEFAULT,
+    day12 => Example::DEFAULT,
+    day13 => Example::DEFAULT,
+    day14 => Example::DEFAULT,
+    // Parsed with the row 10 and the area of 20 the example asks about
+    day15 => Example::DEFAULT,
+    day16 => Example::DEFAULT,
+    // The first block shows the shapes of the rocks
+    day17 => Example { input: 1, ..Example::DEFAULT },
+    day18 => Example::DEFAULT,
+    // Part two only emphasizes the geodes of each blueprint, not their product
+    day19 => Example { skip: &[Part::Two], ..Example::DEFAULT },
+    day20 => Example::DEFAULT,
+    day21 => Example::DEFAULT,
+    day22 => Example::DEFAULT,
+    day23 => Example::DEFAULT,
+    // The first map and its blizzards are too simple to have an answer
+    day24 => Example { input: 6, ..Example::DEFAULT },
+    day25 => Example::DEFAULT,
+}
+
+#[test]
+fn test_sections() {
+    let source = "//! # Day 0\n//!\n//! ```text\n//!   1\n//! 2\n//! ```\n//!\n//! Gives _`3`_, not _`4`_, but _`5`_.\n//!\n//! ## --- Part Two ---\n//!\n//! Now _`6`_:\n//! * `abc`: _`7`_\n//! ```text\n//! 8\n//!  ```\n\nuse crate::prelude::*;\n//! _`7`_";
+    let sections = sections(source);
+    assert_eq!(2, sections.len());
+    assert_eq!(vec!["  1\n2"], sections[0].blocks);
+    assert_eq!(vec!["3", "4", "5"], sections[0].answers);
+    assert!(sections[0].inline.is_empty());
+    assert_eq!(vec!["8"], sections[1].blocks);
+    assert_eq!(vec!["6", "7"], sections[1].answers);
+    assert_eq!(
+        vec![("abc".to_string(), "7".to_string())],
+        sections[1].inline
+    );
+}
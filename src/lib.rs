@@ -1,32 +1,42 @@
 #![allow(
     clippy::type_complexity,
+    clippy::doc_overindented_list_items,
 )]
 
+pub mod answers;
+#[cfg(test)]
+mod examples;
+mod grid;
 mod prelude;
+pub mod report;
+pub mod solution;
 
 mod day01;
 mod day02;
 mod day03;
 mod day04;
 mod day05;
-// mod day06;
-// mod day07;
-// mod day08;
-// mod day09;
-// mod day10;
-// mod day11;
-// mod day12;
-// mod day13;
-// mod day14;
-// mod day15;
-// mod day16;
-// mod day17;
-// mod day18;
-// mod day19;
-// mod day20;
-// mod day21;
-// mod day22;
-// mod day23;
-// mod day24;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
 
-aoc_runner_derive::aoc_lib! { year = 2022 }
+pub use answers::{Answers, Check, ANSWERS_PATH};
+pub use report::{Report, RunReport};
+pub use solution::{find, Entry, Part, Run, Solution, Solver, Variant, SOLUTIONS};
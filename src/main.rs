@@ -1 +1,265 @@
-aoc_runner_derive::aoc_main! { lib = advent_of_code_2022 }
+use advent_of_code_2022::{
+    find, Answers, Check, Entry, Part, Report, RunReport, Solver, ANSWERS_PATH, SOLUTIONS,
+};
+use anyhow::{anyhow, Context, Result};
+use std::io::Read;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: advent-of-code-2022 [--input FILE [--example]] [--json] [DAY [PART [VARIANT]]]
+
+Runs the solutions of Advent of Code 2022.
+
+Arguments:
+  DAY        Day to run, like `day2` or `2`. Runs all days if omitted.
+  PART       Part to run, like `part1` or `1`. Runs both parts if omitted.
+  VARIANT    Name of an implementation, like `Simple` or `Complex`.
+             Runs all implementations if omitted.
+
+Options:
+  -i, --input FILE  Read the puzzle input from FILE, or from stdin if FILE is `-`.
+                    Defaults to `input/2022/dayN.txt`; only allowed with a DAY.
+      --example     The input is the example from the puzzle description,
+                    for days where it uses other parameters, like a smaller area.
+      --json        Print a JSON report with the answers, timings and input hashes
+                    of all runs instead of one line per run.
+  -h, --help        Print this help.
+
+The answers are checked against `input/2022/answers.json` where it has them.
+Exits with a non-zero status if any solver fails or gives a wrong answer.";
+
+/// What to run, from the command line arguments.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Args {
+    day: Option<u32>,
+    part: Option<Part>,
+    variant: Option<String>,
+    input: Option<String>,
+    example: bool,
+    json: bool,
+    help: bool,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args> {
+    let mut parsed = Args::default();
+    let mut positional = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => parsed.help = true,
+            "--json" => parsed.json = true,
+            "--example" => parsed.example = true,
+            "-i" | "--input" => {
+                parsed.input = Some(
+                    args.next()
+                        .ok_or_else(|| anyhow!("Missing file after {}", arg))?,
+                )
+            }
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(anyhow!("Unknown option: {}", arg))
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    if let Some(day) = positional.next() {
+        let number = day.strip_prefix("day").unwrap_or(&day);
+        parsed.day = Some(
+            number
+                .parse()
+                .map_err(|_| anyhow!("Invalid day: {}", day))?,
+        );
+    }
+    if let Some(part) = positional.next() {
+        parsed.part = Some(part.parse()?);
+    }
+    parsed.variant = positional.next();
+    if let Some(extra) = positional.next() {
+        return Err(anyhow!("Unexpected argument: {}", extra));
+    }
+    if parsed.input.is_some() && parsed.day.is_none() {
+        return Err(anyhow!(
+            "--input needs a DAY, as each day has its own input"
+        ));
+    }
+    if parsed.example && parsed.input.is_none() {
+        return Err(anyhow!("--example needs the --input holding the example"));
+    }
+    Ok(parsed)
+}
+
+fn read_input(path: &str) -> Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read the input from stdin")?;
+        Ok(input)
+    } else {
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read {path}"))
+    }
+}
+
+fn default_input_path(entry: &Entry) -> String {
+    format!("input/2022/day{}.txt", entry.day())
+}
+
+/// Run the selected solvers of a day and add them to the report.
+///
+/// Unless a JSON report was asked for, the answers are also printed as they come in.
+/// Returns false if nothing matches the selected part and variant, failed runs are only recorded in the report.
+fn run_day(
+    entry: &Entry,
+    path: &str,
+    input: &str,
+    args: &Args,
+    answers: &Answers,
+    report: &mut Report,
+) -> bool {
+    let solvers = entry
+        .solvers()
+        .into_iter()
+        .filter(|s| args.part.is_none_or(|part| s.part == part))
+        .filter(|s| args.variant.is_none() || s.variant == args.variant.as_deref())
+        .collect::<Vec<Solver>>();
+    if solvers.is_empty() {
+        eprintln!(
+            "Day {}: nothing matches the selected part and variant",
+            entry.day()
+        );
+        return false;
+    }
+
+    for solver in solvers {
+        let run = if args.example {
+            entry.run_example(input, solver)
+        } else {
+            entry.run_timed(input, solver)
+        };
+        let run = RunReport::new(entry, solver, path, input, run, answers);
+        if !args.json {
+            let name = solver
+                .variant
+                .map(|v| format!(" ({v})"))
+                .unwrap_or_default();
+            match (&run.answer, &run.error) {
+                (Some(answer), _) => {
+                    let note = match (run.check, &run.expected) {
+                        (Some(Check::Wrong), Some(expected)) => {
+                            format!(" (wrong, expected {expected})")
+                        }
+                        (Some(Check::Unknown), _) => " (not known yet)".to_string(),
+                        (Some(Check::Missing), _) => " (not in answers)".to_string(),
+                        _ => String::new(),
+                    };
+                    println!(
+                        "Day {} part {}{name}: {answer}{note}",
+                        entry.day(),
+                        solver.part
+                    )
+                }
+                (_, error) => eprintln!(
+                    "Day {} part {}{name}: error: {}",
+                    entry.day(),
+                    solver.part,
+                    error.as_deref().unwrap_or_default()
+                ),
+            }
+        }
+        report.runs.push(run);
+    }
+    true
+}
+
+fn run(args: &Args) -> Result<bool> {
+    let answers = Answers::load(ANSWERS_PATH)?;
+    let mut report = Report::default();
+    let mut ok = true;
+    if let Some(day) = args.day {
+        let entry = find(day).ok_or_else(|| anyhow!("There is no solution for day {}", day))?;
+        let path = args
+            .input
+            .clone()
+            .unwrap_or_else(|| default_input_path(entry));
+        let input = read_input(&path)?;
+        ok &= run_day(entry, &path, &input, args, &answers, &mut report);
+    } else {
+        // Days without an input file are skipped, so that a partial set of inputs can still be checked
+        for entry in SOLUTIONS {
+            let path = default_input_path(entry);
+            match std::fs::read_to_string(&path) {
+                Ok(input) => ok &= run_day(entry, &path, &input, args, &answers, &mut report),
+                Err(_) => eprintln!("Day {}: skipped, no input at {path}", entry.day()),
+            }
+        }
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    }
+    Ok(ok && report.success())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    if args.help {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+fn args(s: &str) -> Result<Args> {
+    parse_args(s.split_whitespace().map(String::from))
+}
+
+#[test]
+fn test_parse_args() -> Result<()> {
+    assert_eq!(Args::default(), args("")?);
+    let expected = Args {
+        day: Some(2),
+        part: Some(Part::One),
+        variant: Some("Simple".to_string()),
+        ..Args::default()
+    };
+    assert_eq!(expected, args("day2 part1 Simple")?);
+    assert_eq!(expected, args("2 1 Simple")?);
+
+    let parsed = args("--input - day6 2")?;
+    assert_eq!(Some("-".to_string()), parsed.input);
+    assert_eq!((Some(6), Some(Part::Two)), (parsed.day, parsed.part));
+    assert!(args("-h")?.help);
+    assert!(args("--json day1")?.json);
+    assert!(!args("day1")?.json);
+    assert!(args("--example --input example.txt day15")?.example);
+    Ok(())
+}
+
+#[test]
+fn test_parse_args_errors() {
+    assert!(args("dayx").is_err());
+    assert!(args("day1 part3").is_err());
+    assert!(args("day1 part1 Simple extra").is_err());
+    assert!(args("--input").is_err());
+    assert!(args("--verbose").is_err());
+    // Every day has its own input
+    assert!(args("--input day1.txt").is_err());
+    // The example is never the default input
+    assert!(args("--example day15").is_err());
+}
@@ -0,0 +1,161 @@
+//! Machine-readable reports of runs, for comparing answers and timings between runs and machines.
+
+use crate::answers::{Answers, Check};
+use crate::prelude::*;
+use crate::solution::{Entry, Run, Solver};
+
+/// All runs of one invocation of the runner.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub runs: Vec<RunReport>,
+}
+
+impl Report {
+    /// Whether every run succeeded without giving a wrong answer.
+    pub fn success(&self) -> bool {
+        self.runs
+            .iter()
+            .all(|run| run.success && run.check != Some(Check::Wrong))
+    }
+}
+
+/// A single day, part and variant run on one input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunReport {
+    pub day: u32,
+    pub part: u32,
+    /// Name of the implementation, `None` for the main implementation of a day without variants
+    pub variant: Option<String>,
+    /// Where the input was read from, `-` for stdin
+    pub input: String,
+    /// See [`input_hash`]
+    pub input_hash: String,
+    pub success: bool,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    /// How the answer compares to the recorded one, `None` if there is no answer
+    pub check: Option<Check>,
+    /// The recorded answer, if known
+    pub expected: Option<String>,
+    pub parse_time_ns: u64,
+    pub solve_time_ns: u64,
+}
+
+impl RunReport {
+    pub fn new(
+        entry: &Entry,
+        solver: Solver,
+        input_path: &str,
+        input: &str,
+        run: Run,
+        answers: &Answers,
+    ) -> Self {
+        let (answer, error) = match run.answer {
+            Ok(answer) => (Some(answer), None),
+            Err(err) => (None, Some(format!("{err:#}"))),
+        };
+        let hash = input_hash(input);
+        let check = answer
+            .as_deref()
+            .map(|answer| answers.check(entry.day(), solver.part, &hash, answer));
+        let expected = answers
+            .get(entry.day(), solver.part, &hash)
+            .and_then(|a| a.answer.clone());
+        Self {
+            day: entry.day(),
+            part: solver.part.number(),
+            variant: solver.variant.map(str::to_string),
+            input: input_path.to_string(),
+            input_hash: hash,
+            success: error.is_none(),
+            answer,
+            error,
+            check,
+            expected,
+            parse_time_ns: run.parse_time.as_nanos().try_into().unwrap_or(u64::MAX),
+            solve_time_ns: run.solve_time.as_nanos().try_into().unwrap_or(u64::MAX),
+        }
+    }
+}
+
+/// Identifies an input, to tell whether two runs used the same one and to look up its answers.
+///
+/// This is the FNV-1a hash of the input as 16 hex digits.
+/// Trailing whitespace is ignored like in the runner, so it does not matter whether the file ends with a newline.
+pub fn input_hash(input: &str) -> String {
+    format!("{:016x}", fnv1a(input.trim_end().as_bytes()))
+}
+
+/// 64 bit FNV-1a, which unlike the standard library hashers is stable across machines and Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[test]
+fn test_fnv1a() {
+    assert_eq!(0xcbf29ce484222325, fnv1a(b""));
+    assert_eq!(0xaf63dc4c8601ec8c, fnv1a(b"a"));
+    assert_eq!(0x85944171f73967e8, fnv1a(b"foobar"));
+    assert_eq!(input_hash("1\n2"), input_hash("1\n2\n"));
+}
+
+#[test]
+fn test_report() -> Result<()> {
+    let entry = crate::solution::find(2).unwrap();
+    let input = "A Y\nB X\nC Z\n";
+    let answers = Answers::from_json(&format!(
+        r#"[{{"day": 2, "part": 1, "input": "{}", "answer": "15"}}]"#,
+        input_hash(input)
+    ))?;
+    let report = Report {
+        runs: entry
+            .solvers()
+            .into_iter()
+            .map(|solver| {
+                let run = entry.run_timed(input, solver);
+                RunReport::new(entry, solver, "-", input, run, &answers)
+            })
+            .collect(),
+    };
+    assert!(report.success());
+    assert_eq!(Some("15".to_string()), report.runs[0].answer);
+    assert_eq!(Some(Check::Correct), report.runs[0].check);
+    assert_eq!(Some(Check::Missing), report.runs[1].check);
+    assert_eq!(Some("Simple".to_string()), report.runs[2].variant);
+
+    let json: serde_json::Value = serde_json::to_value(&report)?;
+    let run = &json["runs"][1];
+    assert_eq!(2, run["part"]);
+    assert_eq!("12", run["answer"]);
+    assert_eq!("missing", run["check"]);
+    assert_eq!(input_hash(input), run["input_hash"]);
+    assert!(run["error"].is_null());
+    assert!(run["solve_time_ns"].is_u64());
+    assert_eq!(report, serde_json::from_value(json)?);
+
+    let wrong = Answers::from_json(&format!(
+        r#"[{{"day": 2, "part": 1, "input": "{}", "answer": "16"}}]"#,
+        input_hash(input)
+    ))?;
+    let solver = entry.solvers()[0];
+    let run = RunReport::new(
+        entry,
+        solver,
+        "-",
+        input,
+        entry.run_timed(input, solver),
+        &wrong,
+    );
+    assert_eq!(Some("16".to_string()), run.expected);
+    assert!(!Report { runs: vec![run] }.success());
+
+    let day20 = crate::solution::find(20).unwrap();
+    let solver = day20.solvers()[0];
+    let run = day20.run_timed("x", solver);
+    let failed = RunReport::new(day20, solver, "-", "x", run, &Answers::default());
+    assert!(!failed.success);
+    assert!(failed.error.is_some() && failed.answer.is_none() && failed.check.is_none());
+    Ok(())
+}
@@ -0,0 +1,355 @@
+//! The interface shared by all days, and the registry of every implemented day.
+
+use crate::prelude::*;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+/// One of the two puzzles of a day.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// The number of the part, 1 or 2
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("part").unwrap_or(s) {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(anyhow!("Invalid part: {}", s)),
+        }
+    }
+}
+
+/// An alternative implementation of a part, next to the main `part1` and `part2`.
+pub struct Variant<I> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&I) -> Result<String>,
+}
+
+/// The solution of a single day.
+///
+/// The puzzle input is parsed once into [`Solution::Input`], which is then shared by both parts and all variants.
+pub trait Solution {
+    type Input;
+
+    /// Day of the advent calendar, starting at 1
+    const DAY: u32;
+
+    /// The parts which have a puzzle. The last day only has one.
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+
+    /// Name of the main implementation, so it can be told apart from the [`Solution::variants`]
+    const NAME: Option<&'static str> = None;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parse the example from the puzzle description.
+    ///
+    /// Only needed for days whose example uses other parameters than the real puzzle, like a smaller area.
+    fn parse_example(input: &str) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String>;
+
+    fn part2(_input: &Self::Input) -> Result<String> {
+        Err(anyhow!("Day {} has no part 2", Self::DAY))
+    }
+
+    /// Alternative implementations, for comparing approaches.
+    fn variants() -> Vec<Variant<Self::Input>> {
+        Vec::new()
+    }
+}
+
+/// Conversion of the different answer types of the parts into the displayed answer.
+pub(crate) trait IntoAnswer {
+    fn into_answer(self) -> Result<String>;
+}
+
+macro_rules! impl_into_answer {
+    ($($t:ty),*) => {
+        $(impl IntoAnswer for $t {
+            fn into_answer(self) -> Result<String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+impl_into_answer!(i32, i64, u32, u64, usize, String);
+
+impl<T: IntoAnswer> IntoAnswer for Result<T> {
+    fn into_answer(self) -> Result<String> {
+        self?.into_answer()
+    }
+}
+
+/// A way to solve a part, as listed by [`Entry::solvers`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Solver {
+    pub part: Part,
+    /// Name of the implementation, `None` for the main implementation of a day without a [`Solution::NAME`]
+    pub variant: Option<&'static str>,
+}
+
+/// Outcome of running a solver on an input.
+#[derive(Debug)]
+pub struct Run {
+    pub answer: Result<String>,
+    /// Time spent in [`Solution::parse`]
+    pub parse_time: Duration,
+    /// Time spent solving the part, zero if parsing failed
+    pub solve_time: Duration,
+}
+
+/// A day in the registry, with the input type of its [`Solution`] erased.
+pub struct Entry {
+    day: u32,
+    solvers: fn() -> Vec<Solver>,
+    run: fn(&str, Solver, bool) -> Run,
+}
+
+impl Entry {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solvers: solvers::<S>,
+            run: run::<S>,
+        }
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// The main implementation of each part, followed by the variants.
+    pub fn solvers(&self) -> Vec<Solver> {
+        (self.solvers)()
+    }
+
+    /// Parse `input` and solve it with `solver`.
+    pub fn run(&self, input: &str, solver: Solver) -> Result<String> {
+        (self.run)(input, solver, false).answer
+    }
+
+    /// Like [`Entry::run`], but also measure how long parsing and solving took.
+    pub fn run_timed(&self, input: &str, solver: Solver) -> Run {
+        (self.run)(input, solver, false)
+    }
+
+    /// Like [`Entry::run_timed`], but for the example from the puzzle description, see [`Solution::parse_example`].
+    pub fn run_example(&self, input: &str, solver: Solver) -> Run {
+        (self.run)(input, solver, true)
+    }
+}
+
+fn solvers<S: Solution>() -> Vec<Solver> {
+    let main = S::PARTS.iter().map(|&part| Solver {
+        part,
+        variant: S::NAME,
+    });
+    let variants = S::variants().into_iter().map(|v| Solver {
+        part: v.part,
+        variant: Some(v.name),
+    });
+    main.chain(variants).collect()
+}
+
+fn run<S: Solution>(input: &str, solver: Solver, example: bool) -> Run {
+    let mut run = Run {
+        answer: Err(anyhow!("Not run")),
+        parse_time: Duration::ZERO,
+        solve_time: Duration::ZERO,
+    };
+    let solve = match find_solver::<S>(solver) {
+        Ok(solve) => solve,
+        Err(err) => {
+            run.answer = Err(err);
+            return run;
+        }
+    };
+
+    // Input files end with a newline, which none of the parsers expect
+    let start = Instant::now();
+    let parse = if example { S::parse_example } else { S::parse };
+    let parsed = catch_panic(|| parse(input.trim_end()));
+    run.parse_time = start.elapsed();
+    run.answer = parsed.and_then(|parsed| {
+        let start = Instant::now();
+        let answer = catch_panic(|| solve(&parsed));
+        run.solve_time = start.elapsed();
+        answer
+    });
+    run
+}
+
+/// Turn a panic in `f` into an error, so that one bad run does not take down all the others.
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(anyhow!("Panicked: {message}"))
+    })
+}
+
+fn find_solver<S: Solution>(solver: Solver) -> Result<fn(&S::Input) -> Result<String>> {
+    let solve = match solver.variant {
+        Some(name) if solver.variant != S::NAME => {
+            S::variants()
+                .into_iter()
+                .find(|v| v.part == solver.part && v.name == name)
+                .ok_or_else(|| {
+                    anyhow!("Day {} part {} has no variant {}", S::DAY, solver.part, name)
+                })?
+                .solve
+        }
+        // The main implementation, which can also be selected without its name
+        _ if !S::PARTS.contains(&solver.part) => {
+            return Err(anyhow!("Day {} has no part {}", S::DAY, solver.part))
+        }
+        _ if solver.part == Part::One => S::part1,
+        _ => S::part2,
+    };
+    Ok(solve)
+}
+
+/// All days, in order.
+pub static SOLUTIONS: &[Entry] = &[
+    crate::day01::ENTRY,
+    crate::day02::ENTRY,
+    crate::day03::ENTRY,
+    crate::day04::ENTRY,
+    crate::day05::ENTRY,
+    crate::day06::ENTRY,
+    crate::day07::ENTRY,
+    crate::day08::ENTRY,
+    crate::day09::ENTRY,
+    crate::day10::ENTRY,
+    crate::day11::ENTRY,
+    crate::day12::ENTRY,
+    crate::day13::ENTRY,
+    crate::day14::ENTRY,
+    crate::day15::ENTRY,
+    crate::day16::ENTRY,
+    crate::day17::ENTRY,
+    crate::day18::ENTRY,
+    crate::day19::ENTRY,
+    crate::day20::ENTRY,
+    crate::day21::ENTRY,
+    crate::day22::ENTRY,
+    crate::day23::ENTRY,
+    crate::day24::ENTRY,
+    crate::day25::ENTRY,
+];
+
+/// Look up a day in the registry.
+pub fn find(day: u32) -> Option<&'static Entry> {
+    SOLUTIONS.iter().find(|entry| entry.day == day)
+}
+
+#[test]
+fn test_registry() {
+    assert_eq!((1..=25).collect_vec(), SOLUTIONS.iter().map(Entry::day).collect_vec());
+    // Day 25 only has one part
+    assert_eq!(1, find(25).unwrap().solvers().len());
+    let day2 = find(2).unwrap().solvers();
+    assert!(day2.contains(&Solver {
+        part: Part::Two,
+        variant: Some("Simple"),
+    }));
+    // The main implementations of days with variants have a name too
+    assert!(day2.contains(&Solver {
+        part: Part::One,
+        variant: Some("Complex"),
+    }));
+    assert!(find(6).unwrap().solvers().contains(&Solver {
+        part: Part::Two,
+        variant: Some("Window"),
+    }));
+}
+
+#[test]
+fn test_run() -> Result<()> {
+    let input = "A Y\nB X\nC Z";
+    let day2 = find(2).unwrap();
+    for solver in day2.solvers() {
+        let expected = if solver.part == Part::One { "15" } else { "12" };
+        assert_eq!(expected, day2.run(input, solver)?, "{solver:?}");
+    }
+    let missing = Solver {
+        part: Part::Two,
+        variant: None,
+    };
+    assert!(find(25).unwrap().run("1", missing).is_err());
+    // Also runs the main implementation of day 2, which is named `Complex`
+    assert_eq!("12", day2.run(input, missing)?);
+    let unknown = Solver {
+        part: Part::One,
+        variant: Some("Fast"),
+    };
+    assert!(day2.run(input, unknown).is_err());
+    Ok(())
+}
+
+/// A day whose first part always panics, for testing [`catch_panic`].
+#[cfg(test)]
+struct Panicking;
+
+#[cfg(test)]
+impl Solution for Panicking {
+    type Input = ();
+
+    const DAY: u32 = 0;
+
+    fn parse(_input: &str) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<String> {
+        panic!("no answer")
+    }
+}
+
+#[test]
+fn test_run_panic() {
+    let solver = Solver {
+        part: Part::One,
+        variant: None,
+    };
+    let run = Entry::new::<Panicking>().run_timed("", solver);
+    assert_eq!("Panicked: no answer", run.answer.unwrap_err().to_string());
+    // Errors without a panic are passed on unchanged
+    let solver = Solver {
+        part: Part::Two,
+        variant: None,
+    };
+    assert_eq!(
+        "Day 0 has no part 2",
+        Entry::new::<Panicking>().run("", solver).unwrap_err().to_string()
+    );
+}